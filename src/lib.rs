@@ -1,4 +1,5 @@
 pub mod individual;
 pub mod moea;
 pub mod operators;
+pub mod packed_individual;
 pub mod problems;
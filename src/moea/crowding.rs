@@ -1 +1,69 @@
 //! クラウディング距離計算
+
+use crate::individual::Objectives;
+
+/// 同じフロントに属する点のクラウディング距離
+///
+/// 目的関数ごとに点を並べ、両隣の点との差を値の範囲で正規化して足し合わせる。
+/// 各目的関数で両端となる点は無限大とする。
+pub fn crowding_distance(front: &[Objectives]) -> Vec<f64> {
+    let n = front.len();
+    let mut distance = vec![0.0; n];
+    if n == 0 {
+        return distance;
+    }
+
+    let number_of_obj = front[0].len();
+    (0..number_of_obj).for_each(|k| {
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&a, &b| front[a][k].total_cmp(&front[b][k]));
+
+        let min = front[order[0]][k];
+        let max = front[order[n - 1]][k];
+        distance[order[0]] = f64::INFINITY;
+        distance[order[n - 1]] = f64::INFINITY;
+
+        // 値がすべて等しい、または無限大を含む場合は両端以外に加えない
+        let range = max - min;
+        if !(range.is_finite() && range > 0.0) {
+            return;
+        }
+        order.windows(3).for_each(|w| {
+            distance[w[1]] += (front[w[2]][k] - front[w[0]][k]) / range;
+        });
+    });
+
+    distance
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crowding_distance() {
+        let front = vec![
+            vec![0.0, 4.0],
+            vec![1.0, 2.0],
+            vec![3.0, 1.0],
+            vec![4.0, 0.0],
+        ];
+        let distance = crowding_distance(&front);
+        // 両端は無限大
+        assert_eq!(distance[0], f64::INFINITY);
+        assert_eq!(distance[3], f64::INFINITY);
+        // (3 - 0) / 4 + (4 - 1) / 4
+        assert_eq!(distance[1], 1.5);
+        // (4 - 1) / 4 + (2 - 0) / 4
+        assert_eq!(distance[2], 1.25);
+    }
+
+    #[test]
+    fn test_crowding_distance_small_front() {
+        assert!(crowding_distance(&[]).is_empty());
+        assert_eq!(
+            crowding_distance(&[vec![1.0, 2.0]]),
+            vec![f64::INFINITY]
+        );
+    }
+}
@@ -0,0 +1,11 @@
+//! 世代ごとの改善ステップ
+
+/// NSGA-IIの各世代で集団に適用する改善ステップ（メメティック探索）
+///
+/// [`environmental_selection`](super::environmental_selection) が親集団と子集団を
+/// 合わせた後に `improve` を呼び出し、返された集団に対して非支配ソートと
+/// 混雑距離による選択を行う。返す集団は元の集団より大きくてもよい。
+pub trait ImprovementStep<S> {
+    /// 集団を改善し、環境選択の候補となる集団を返す
+    fn improve(&mut self, population: &[S]) -> Vec<S>;
+}
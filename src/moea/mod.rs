@@ -1,6 +1,11 @@
 mod crowding;
 mod dominance;
+mod improvement;
+mod selection;
 mod sorting;
 
+pub use crowding::*;
 pub use dominance::*;
+pub use improvement::*;
+pub use selection::*;
 pub use sorting::*;
@@ -0,0 +1,201 @@
+//! 環境選択（次世代の集団の選択）
+
+use super::crowding::crowding_distance;
+use super::improvement::ImprovementStep;
+use crate::individual::Objectives;
+use crate::problems::Problem;
+
+/// 評価済みの解
+struct Evaluated {
+    /// 最小化に揃えた目的関数値
+    objectives: Objectives,
+    /// 制約違反量の合計
+    violation: f64,
+}
+
+/// 制約付き支配（Deb の制約処理）で `a` が `b` を支配する場合true
+///
+/// 実行可能解は実行不可能解を支配し、実行不可能解どうしは違反量の小さい方が
+/// 支配する。実行可能解どうしは目的関数値で比較する。
+fn constrained_dominates(a: &Evaluated, b: &Evaluated) -> bool {
+    if a.violation > 0.0 || b.violation > 0.0 {
+        return a.violation < b.violation;
+    }
+    a.objectives.iter().zip(&b.objectives).all(|(x, y)| x <= y)
+        && a.objectives.iter().zip(&b.objectives).any(|(x, y)| x < y)
+}
+
+/// 添字の集合を非支配ソートする
+fn sort_fronts(evaluated: &[Evaluated]) -> Vec<Vec<usize>> {
+    let mut remaining: Vec<usize> = (0..evaluated.len()).collect();
+    let mut fronts = Vec::new();
+    while !remaining.is_empty() {
+        let (front, rest): (Vec<usize>, Vec<usize>) =
+            remaining.iter().partition(|&&i| {
+                !remaining.iter().any(|&j| {
+                    constrained_dominates(&evaluated[j], &evaluated[i])
+                })
+            });
+        fronts.push(front);
+        remaining = rest;
+    }
+    fronts
+}
+
+/// NSGA-IIの環境選択
+///
+/// 親集団と子集団を合わせ、`improvement` があれば改善ステップを適用してから、
+/// 非支配ソートとクラウディング距離で `size` 個の解を選ぶ。
+/// 制約のある問題では制約付き支配で比較する。
+pub fn environmental_selection<P>(
+    problem: &P,
+    parents: &[P::Solution],
+    offspring: &[P::Solution],
+    size: usize,
+    improvement: Option<&mut dyn ImprovementStep<P::Solution>>,
+) -> Vec<P::Solution>
+where
+    P: Problem,
+    P::Solution: Clone,
+{
+    let merged = [parents, offspring].concat();
+    let candidates = match improvement {
+        Some(step) => step.improve(&merged),
+        None => merged,
+    };
+
+    let evaluated: Vec<Evaluated> = candidates
+        .iter()
+        .map(|x| Evaluated {
+            objectives: problem.evaluate_minimised(x),
+            violation: problem.constraint_violation(x).iter().sum(),
+        })
+        .collect();
+
+    let mut selected: Vec<usize> = Vec::with_capacity(size);
+    for front in sort_fronts(&evaluated) {
+        let remaining = size - selected.len();
+        if front.len() <= remaining {
+            selected.extend(front);
+            continue;
+        }
+
+        // 入りきらないフロントはクラウディング距離の大きい順に選ぶ
+        let points: Vec<Objectives> = front
+            .iter()
+            .map(|&i| evaluated[i].objectives.clone())
+            .collect();
+        let distance = crowding_distance(&points);
+        let mut order: Vec<usize> = (0..front.len()).collect();
+        order.sort_by(|&a, &b| distance[b].total_cmp(&distance[a]));
+        selected
+            .extend(order.into_iter().take(remaining).map(|i| front[i]));
+        break;
+    }
+
+    selected
+        .into_iter()
+        .map(|i| candidates[i].clone())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 解をそのまま目的関数値とし、`x[0] <= limit` を制約とする問題
+    struct Identity {
+        limit: f64,
+    }
+
+    impl Problem for Identity {
+        type Solution = Vec<f64>;
+
+        fn number_of_objectives(&self) -> usize {
+            2
+        }
+
+        fn evaluate(&self, x: &Self::Solution) -> Objectives {
+            x.clone()
+        }
+
+        fn number_of_constraints(&self) -> usize {
+            1
+        }
+
+        fn constraint_violation(&self, x: &Self::Solution) -> Vec<f64> {
+            vec![(x[0] - self.limit).max(0.0)]
+        }
+    }
+
+    /// 呼び出し回数を数え、与えられた解を追加する改善ステップ
+    struct AddSolution {
+        calls: usize,
+        solution: Vec<f64>,
+    }
+
+    impl ImprovementStep<Vec<f64>> for AddSolution {
+        fn improve(&mut self, population: &[Vec<f64>]) -> Vec<Vec<f64>> {
+            self.calls += 1;
+            let mut result = population.to_vec();
+            result.push(self.solution.clone());
+            result
+        }
+    }
+
+    #[test]
+    fn test_environmental_selection() {
+        let problem = Identity { limit: 10.0 };
+        let parents = vec![vec![0.0, 4.0], vec![2.0, 2.0], vec![3.0, 3.0]];
+        let offspring = vec![vec![4.0, 0.0], vec![1.0, 3.5]];
+        // 第1フロントは4点で、両端と (1, 3.5) (2, 2) のうち混雑していない方
+        let selected = environmental_selection(
+            &problem, &parents, &offspring, 3, None,
+        );
+        assert_eq!(selected.len(), 3);
+        assert!(selected.contains(&vec![0.0, 4.0]));
+        assert!(selected.contains(&vec![4.0, 0.0]));
+        assert!(selected.contains(&vec![2.0, 2.0]));
+        // 候補より多く求めた場合はすべて残る
+        let all = environmental_selection(
+            &problem, &parents, &offspring, 10, None,
+        );
+        assert_eq!(all.len(), 5);
+    }
+
+    #[test]
+    fn test_environmental_selection_constraint() {
+        // 制約違反のある解は、目的関数値が良くても実行可能解の後になる
+        let problem = Identity { limit: 1.0 };
+        let parents = vec![vec![0.0, 5.0], vec![1.0, 6.0]];
+        let offspring = vec![vec![2.0, 0.0], vec![3.0, 0.0]];
+        let selected = environmental_selection(
+            &problem, &parents, &offspring, 3, None,
+        );
+        assert_eq!(
+            selected,
+            vec![vec![0.0, 5.0], vec![1.0, 6.0], vec![2.0, 0.0]]
+        );
+    }
+
+    #[test]
+    fn test_environmental_selection_with_improvement() {
+        // 改善ステップは1回だけ呼ばれ、追加された解も選択の候補になる
+        let problem = Identity { limit: 10.0 };
+        let parents = vec![vec![2.0, 2.0]];
+        let offspring = vec![vec![3.0, 3.0]];
+        let mut step = AddSolution {
+            calls: 0,
+            solution: vec![1.0, 1.0],
+        };
+        let selected = environmental_selection(
+            &problem,
+            &parents,
+            &offspring,
+            1,
+            Some(&mut step),
+        );
+        assert_eq!(step.calls, 1);
+        assert_eq!(selected, vec![vec![1.0, 1.0]]);
+    }
+}
@@ -1,5 +1,6 @@
 //! 交叉に関する処理
 
+use crate::packed_individual::{PackedIndividual, WORD_BITS};
 use crate::types::Individual;
 
 /// 1点交叉を行う。
@@ -39,11 +40,86 @@ pub fn one_point_crossover_random(
     return one_point_crossover(parent1, parent2, point);
 }
 
+/// ワード単位のマスクで交叉を行う。
+///
+/// マスクのビットが1の位置は `parent1` から、0の位置は `parent2` から受け継ぐ。
+/// 2つ目の子はその逆となる。
+fn masked_crossover(
+    parent1: &PackedIndividual,
+    parent2: &PackedIndividual,
+    masks: impl Iterator<Item = u64>,
+) -> (PackedIndividual, PackedIndividual) {
+    let len = parent1.len();
+    let (words1, words2): (Vec<u64>, Vec<u64>) = parent1
+        .words()
+        .iter()
+        .zip(parent2.words().iter())
+        .zip(masks)
+        .map(|((a, b), m)| ((a & m) | (b & !m), (b & m) | (a & !m)))
+        .unzip();
+
+    (
+        PackedIndividual::from_words(words1, len),
+        PackedIndividual::from_words(words2, len),
+    )
+}
+
+/// ビットを詰めた個体に対して1点交叉を行う。
+///
+/// 交叉点より前のワードはそのまま、交叉点を含むワードはマスクで分割し、
+/// 交叉点より後ろのワードは入れ換える。
+fn packed_one_point_crossover(
+    parent1: &PackedIndividual,
+    parent2: &PackedIndividual,
+    point: usize,
+) -> (PackedIndividual, PackedIndividual) {
+    assert_eq!(parent1.len(), parent2.len(), "ビット数が異なる");
+    let masks = (0..parent1.words().len()).map(|w| {
+        let start = w * WORD_BITS;
+        if point >= start + WORD_BITS {
+            u64::MAX
+        } else if point <= start {
+            0
+        } else {
+            (1u64 << (point - start)) - 1
+        }
+    });
+
+    masked_crossover(parent1, parent2, masks)
+}
+
+/// ビットを詰めた個体に対して、ランダムな点で1点交叉を行う。
+pub fn packed_one_point_crossover_random(
+    parent1: &PackedIndividual,
+    parent2: &PackedIndividual,
+) -> (PackedIndividual, PackedIndividual) {
+    let point = rand::random_range(0..parent1.len());
+
+    packed_one_point_crossover(parent1, parent2, point)
+}
+
+/// ビットを詰めた個体に対して一様交叉を行う。
+///
+/// ワードごとにランダムな64ビットのマスクを生成し、各ビットを確率0.5で入れ換える。
+pub fn packed_uniform_crossover(
+    parent1: &PackedIndividual,
+    parent2: &PackedIndividual,
+) -> (PackedIndividual, PackedIndividual) {
+    assert_eq!(parent1.len(), parent2.len(), "ビット数が異なる");
+    let masks = (0..parent1.words().len()).map(|_| rand::random::<u64>());
+
+    masked_crossover(parent1, parent2, masks)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::types::Individual;
 
-    use super::one_point_crossover;
+    use super::{
+        one_point_crossover, packed_one_point_crossover,
+        packed_uniform_crossover,
+    };
+    use crate::packed_individual::PackedIndividual;
 
     fn create_parent() -> (Individual, Individual) {
         return (
@@ -99,4 +175,35 @@ mod tests {
         assert_eq!(child1, vec![1, 2, 6, 7, 8]);
         assert_eq!(child2, vec![4, 5, 3]);
     }
+
+    #[test]
+    fn test_packed_one_point_crossover_matches_unpacked() {
+        // 複数ワードにまたがる個体で、通常の1点交叉と結果が一致する
+        let parent1: Individual =
+            (0..150).map(|i| (i % 2) as u8).collect();
+        let parent2: Individual =
+            (0..150).map(|i| (i % 3 == 0) as u8).collect();
+        let packed1 = PackedIndividual::from_individual(&parent1);
+        let packed2 = PackedIndividual::from_individual(&parent2);
+
+        for point in [0, 1, 63, 64, 100, 128, 150] {
+            let (child1, child2) =
+                one_point_crossover(&parent1, &parent2, point);
+            let (packed_child1, packed_child2) =
+                packed_one_point_crossover(&packed1, &packed2, point);
+            assert_eq!(packed_child1.to_individual(), child1);
+            assert_eq!(packed_child2.to_individual(), child2);
+        }
+    }
+
+    #[test]
+    fn test_packed_uniform_crossover_preserves_bits() {
+        // 各位置のビットは両親のどちらかから受け継がれ、両方の子で合計が保存される
+        let parent1 = PackedIndividual::from_individual(&vec![1; 100]);
+        let parent2 = PackedIndividual::zeros(100);
+        let (child1, child2) =
+            packed_uniform_crossover(&parent1, &parent2);
+        assert_eq!(child1.count_ones() + child2.count_ones(), 100);
+        assert_eq!(child1.hamming_distance(&child2), 100);
+    }
 }
@@ -0,0 +1,90 @@
+//! 子個体の重複排除
+
+use std::collections::HashSet;
+
+use crate::types::Individual;
+
+/// 集団または兄弟と同じ遺伝子を持つ子個体を取り除く。
+///
+/// 遺伝子のハッシュで重複を判定する。重複した子個体は `regenerate` で作り直し、
+/// `max_retries` 回作り直しても重複する場合は取り除く。
+/// `max_retries` が0の場合は作り直さずに取り除く。
+///
+/// 返す子個体の数は `offspring` の数以下になる。
+pub fn eliminate_duplicates<F>(
+    population: &[Individual],
+    offspring: Vec<Individual>,
+    max_retries: usize,
+    mut regenerate: F,
+) -> Vec<Individual>
+where
+    F: FnMut() -> Individual,
+{
+    let mut seen: HashSet<Individual> =
+        population.iter().cloned().collect();
+
+    offspring
+        .into_iter()
+        .filter_map(|child| {
+            std::iter::once(child)
+                .chain((0..max_retries).map(|_| regenerate()))
+                .find(|candidate| !seen.contains(candidate))
+                .inspect(|unique| {
+                    seen.insert(unique.clone());
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eliminate_duplicates_rejects_population_member() {
+        // 集団と同じ遺伝子の子個体は取り除かれる
+        let population = vec![vec![1, 0, 1], vec![0, 0, 0]];
+        let offspring = vec![vec![1, 0, 1], vec![1, 1, 1]];
+        let result =
+            eliminate_duplicates(&population, offspring, 0, || vec![0; 3]);
+        assert_eq!(result, vec![vec![1, 1, 1]]);
+    }
+
+    #[test]
+    fn test_eliminate_duplicates_rejects_sibling() {
+        // 兄弟と同じ遺伝子の子個体は取り除かれる
+        let population = vec![];
+        let offspring = vec![vec![1, 1, 0], vec![1, 1, 0], vec![0, 1, 0]];
+        let result =
+            eliminate_duplicates(&population, offspring, 0, || vec![0; 3]);
+        assert_eq!(result, vec![vec![1, 1, 0], vec![0, 1, 0]]);
+    }
+
+    #[test]
+    fn test_eliminate_duplicates_regenerates() {
+        // 重複した子個体は作り直され、重複しない個体が採用される
+        let population = vec![vec![0, 0]];
+        let offspring = vec![vec![0, 0]];
+        let mut candidates = vec![vec![1, 1], vec![0, 0]];
+        let result =
+            eliminate_duplicates(&population, offspring, 3, || {
+                candidates.pop().unwrap()
+            });
+        assert_eq!(result, vec![vec![1, 1]]);
+    }
+
+    #[test]
+    fn test_eliminate_duplicates_gives_up_after_retries() {
+        // 作り直しても重複する場合は取り除かれる
+        let population = vec![vec![0, 0]];
+        let offspring = vec![vec![0, 0]];
+        let mut calls = 0;
+        let result =
+            eliminate_duplicates(&population, offspring, 3, || {
+                calls += 1;
+                vec![0, 0]
+            });
+        assert!(result.is_empty());
+        assert_eq!(calls, 3);
+    }
+}
@@ -1,6 +1,17 @@
 mod crossover;
+mod duplicate;
 mod mutation;
+mod permutation;
 mod selection;
 
-pub use crossover::one_point_crossover_random;
+pub use crossover::{
+    one_point_crossover_random, packed_one_point_crossover_random,
+    packed_uniform_crossover,
+};
+pub use duplicate::eliminate_duplicates;
+pub use mutation::packed_bit_flip_mutation;
+pub use permutation::{
+    inversion_mutation, order_crossover_random, random_permutation,
+    swap_mutation,
+};
 // pub use selection::binary_tournament_nsga2;
@@ -1,3 +1,4 @@
+use crate::packed_individual::PackedIndividual;
 use crate::types::Individual;
 
 /// ビット反転突然変異
@@ -26,3 +27,70 @@ fn bit_flip_mutation(prob: f64, ind: &Individual) -> Vec<u8> {
 
 // /// 複数の個体に突然変異を適用する
 // fn mutate_population
+
+/// ビットを詰めた個体に突然変異を適応する
+///
+/// ビットごとに乱数を引く代わりに、次に反転する位置までの間隔を幾何分布から
+/// サンプリングする。反転するビット数の期待値を `k` とすると、乱数の生成回数も
+/// `k` 回程度で済む。
+pub fn packed_bit_flip_mutation(
+    prob: f64,
+    ind: &PackedIndividual,
+) -> PackedIndividual {
+    let mut mutated = ind.clone();
+    if prob <= 0.0 {
+        return mutated;
+    }
+    if prob >= 1.0 {
+        (0..mutated.len()).for_each(|i| mutated.flip(i));
+        return mutated;
+    }
+
+    // 反転しない確率の対数
+    let log_q = (1.0 - prob).ln();
+    let mut pos = 0;
+    loop {
+        let u: f64 = rand::random_range(f64::MIN_POSITIVE..1.0);
+        let skip = (u.ln() / log_q).floor();
+        if skip >= (mutated.len() - pos) as f64 {
+            break;
+        }
+        pos += skip as usize;
+        mutated.flip(pos);
+        pos += 1;
+    }
+
+    mutated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_packed_bit_flip_mutation_zero_prob() {
+        // 確率0の場合、変化しない
+        let ind = PackedIndividual::from_individual(&vec![1, 0, 1, 0]);
+        assert_eq!(packed_bit_flip_mutation(0.0, &ind), ind);
+    }
+
+    #[test]
+    fn test_packed_bit_flip_mutation_full_prob() {
+        // 確率1の場合、すべてのビットが反転する
+        let ind = PackedIndividual::from_individual(&vec![1, 0, 1, 0]);
+        let mutated = packed_bit_flip_mutation(1.0, &ind);
+        assert_eq!(mutated.to_individual(), vec![0, 1, 0, 1]);
+    }
+
+    #[test]
+    fn test_packed_bit_flip_mutation_rate() {
+        // 反転したビット数の平均が期待値 (ビット数 × 確率) に近い
+        let ind = PackedIndividual::zeros(5000);
+        let trials = 20;
+        let flipped: usize = (0..trials)
+            .map(|_| packed_bit_flip_mutation(0.01, &ind).count_ones())
+            .sum();
+        let mean = flipped as f64 / trials as f64;
+        assert!((mean - 50.0).abs() < 10.0, "mean = {}", mean);
+    }
+}
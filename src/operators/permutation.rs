@@ -0,0 +1,152 @@
+//! 順列で表す個体（巡回路、ジョブの処理順など）の初期化・交叉・突然変異
+
+/// `0..n` のランダムな順列を作る（Fisher–Yates シャッフル）
+pub fn random_permutation(n: usize) -> Vec<usize> {
+    let mut perm: Vec<usize> = (0..n).collect();
+    (1..n).rev().for_each(|i| {
+        let j = rand::random_range(0..=i);
+        perm.swap(i, j);
+    });
+    perm
+}
+
+/// 区間 `start..end` で順序交叉（OX）を行う
+///
+/// 子1は `parent1` の区間をそのまま受け継ぎ、残りの位置を区間の直後から
+/// `parent2` に現れる順で埋める。子2は親を入れ換えて同様に作る。
+/// ```
+/// 親A: 12｜345｜678 ⇒ 16 345 827
+/// 親B: 37｜516｜824 ⇒ 34 516 782
+/// ```
+fn order_crossover(
+    parent1: &[usize],
+    parent2: &[usize],
+    start: usize,
+    end: usize,
+) -> (Vec<usize>, Vec<usize>) {
+    /// `donor` の区間を残し、`other` の順で残りを埋めた子
+    fn child(
+        donor: &[usize],
+        other: &[usize],
+        start: usize,
+        end: usize,
+    ) -> Vec<usize> {
+        let n = donor.len();
+        let segment = &donor[start..end];
+        let mut rest = other
+            .iter()
+            .cycle()
+            .skip(end)
+            .take(n)
+            .filter(|v| !segment.contains(v));
+        let mut offspring = donor.to_vec();
+        (end..n).chain(0..start).for_each(|i| {
+            offspring[i] = *rest.next().expect("parents are permutations");
+        });
+        offspring
+    }
+
+    (
+        child(parent1, parent2, start, end),
+        child(parent2, parent1, start, end),
+    )
+}
+
+/// ランダムな区間で順序交叉（OX）を行う
+///
+/// 親はどちらも同じ集合の順列でなければならない。子も順列になる。
+/// ```
+/// 親A: 12｜345｜678 ⇒ 16 345 827
+/// 親B: 37｜516｜824 ⇒ 34 516 782
+/// ```
+pub fn order_crossover_random(
+    parent1: &[usize],
+    parent2: &[usize],
+) -> (Vec<usize>, Vec<usize>) {
+    let n = parent1.len();
+    if n < 2 {
+        return (parent1.to_vec(), parent2.to_vec());
+    }
+    let a = rand::random_range(0..=n);
+    let b = rand::random_range(0..=n);
+    order_crossover(parent1, parent2, a.min(b), a.max(b))
+}
+
+/// 指定された確率で2つの位置の要素を入れ換える（交換突然変異）
+pub fn swap_mutation(prob: f64, ind: &[usize]) -> Vec<usize> {
+    let mut mutated = ind.to_vec();
+    let n = mutated.len();
+    if n >= 2 && rand::random_range(0.0..1.0) < prob {
+        let i = rand::random_range(0..n);
+        let j = rand::random_range(0..n);
+        mutated.swap(i, j);
+    }
+    mutated
+}
+
+/// 指定された確率でランダムな区間の順序を反転する（逆位突然変異）
+///
+/// TSP では2-opt の近傍に相当し、巡回路の2本の辺だけが変わる。
+pub fn inversion_mutation(prob: f64, ind: &[usize]) -> Vec<usize> {
+    let mut mutated = ind.to_vec();
+    let n = mutated.len();
+    if n >= 2 && rand::random_range(0.0..1.0) < prob {
+        let a = rand::random_range(0..=n);
+        let b = rand::random_range(0..=n);
+        mutated[a.min(b)..a.max(b)].reverse();
+    }
+    mutated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_permutation(perm: &[usize], n: usize) -> bool {
+        let mut sorted = perm.to_vec();
+        sorted.sort();
+        sorted == (0..n).collect::<Vec<_>>()
+    }
+
+    #[test]
+    fn test_random_permutation() {
+        // 長さ0と1も含め、常に順列になる
+        (0..10).for_each(|n| {
+            assert!(is_permutation(&random_permutation(n), n))
+        });
+    }
+
+    #[test]
+    fn test_order_crossover() {
+        // ドキュメントの例を 0 始まりにしたもの
+        let parent1 = vec![0, 1, 2, 3, 4, 5, 6, 7];
+        let parent2 = vec![2, 6, 4, 0, 5, 7, 1, 3];
+        let (child1, child2) = order_crossover(&parent1, &parent2, 2, 5);
+        assert_eq!(child1, vec![0, 5, 2, 3, 4, 7, 1, 6]);
+        assert_eq!(child2, vec![2, 3, 4, 0, 5, 6, 7, 1]);
+    }
+
+    #[test]
+    fn test_order_crossover_random_preserves_permutation() {
+        let parent1 = random_permutation(12);
+        let parent2 = random_permutation(12);
+        (0..100).for_each(|_| {
+            let (child1, child2) =
+                order_crossover_random(&parent1, &parent2);
+            assert!(is_permutation(&child1, 12));
+            assert!(is_permutation(&child2, 12));
+        });
+    }
+
+    #[test]
+    fn test_mutation_preserves_permutation() {
+        let ind = random_permutation(12);
+        // 確率0の場合、変化しない
+        assert_eq!(swap_mutation(0.0, &ind), ind);
+        assert_eq!(inversion_mutation(0.0, &ind), ind);
+        (0..100).for_each(|_| {
+            assert!(is_permutation(&swap_mutation(1.0, &ind), 12));
+            assert!(is_permutation(&inversion_mutation(1.0, &ind), 12));
+        });
+    }
+}
@@ -0,0 +1,162 @@
+//! ビットを詰めた遺伝子表現
+//!
+//! `Individual` は1ビットを1バイトで保持するが、`PackedIndividual` は64ビットを
+//! 1つの `u64` に詰めて保持する。アイテム数の多い0/1問題でメモリ使用量を
+//! 1/8に抑え、交叉や距離計算をワード単位で行える。
+
+use crate::individual::Individual;
+
+/// 1ワードあたりのビット数
+pub const WORD_BITS: usize = u64::BITS as usize;
+
+/// ビットを `u64` に詰めた個体
+///
+/// `i` 番目のビットは `words[i / 64]` の下位から `i % 64` 番目に格納する。
+/// 最後のワードの使われないビットは常に0に保つ。
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PackedIndividual {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl PackedIndividual {
+    /// すべてのビットが0の個体を作る
+    pub fn zeros(len: usize) -> Self {
+        Self {
+            words: vec![0; len.div_ceil(WORD_BITS)],
+            len,
+        }
+    }
+
+    /// ワード列から個体を作る
+    ///
+    /// `len` を超える部分のビットは0にする。
+    pub fn from_words(words: Vec<u64>, len: usize) -> Self {
+        assert_eq!(
+            words.len(),
+            len.div_ceil(WORD_BITS),
+            "ワード数がビット数に対応していない"
+        );
+        let mut packed = Self { words, len };
+        packed.clear_unused_bits();
+        packed
+    }
+
+    /// `Individual` からビットを詰めた個体を作る
+    pub fn from_individual(ind: &Individual) -> Self {
+        let mut packed = Self::zeros(ind.len());
+        ind.iter()
+            .enumerate()
+            .filter(|(_, bit)| **bit != 0)
+            .for_each(|(i, _)| packed.set(i, 1));
+        packed
+    }
+
+    /// `Individual` に展開する
+    pub fn to_individual(&self) -> Individual {
+        (0..self.len).map(|i| self.get(i)).collect()
+    }
+
+    /// ビット数
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// ビット数が0の場合true
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// ワード列
+    pub fn words(&self) -> &[u64] {
+        &self.words
+    }
+
+    /// `i` 番目のビットを取得する
+    pub fn get(&self, i: usize) -> u8 {
+        assert!(i < self.len, "ビット位置が範囲外");
+        ((self.words[i / WORD_BITS] >> (i % WORD_BITS)) & 1) as u8
+    }
+
+    /// `i` 番目のビットを設定する
+    pub fn set(&mut self, i: usize, bit: u8) {
+        assert!(i < self.len, "ビット位置が範囲外");
+        let mask = 1u64 << (i % WORD_BITS);
+        if bit == 0 {
+            self.words[i / WORD_BITS] &= !mask;
+        } else {
+            self.words[i / WORD_BITS] |= mask;
+        }
+    }
+
+    /// `i` 番目のビットを反転する
+    pub fn flip(&mut self, i: usize) {
+        assert!(i < self.len, "ビット位置が範囲外");
+        self.words[i / WORD_BITS] ^= 1u64 << (i % WORD_BITS);
+    }
+
+    /// 1のビットの数
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// ハミング距離（異なるビットの数）
+    pub fn hamming_distance(&self, other: &Self) -> usize {
+        assert_eq!(self.len, other.len, "ビット数が異なる");
+        self.words
+            .iter()
+            .zip(other.words.iter())
+            .map(|(a, b)| (a ^ b).count_ones() as usize)
+            .sum()
+    }
+
+    /// 最後のワードの使われないビットを0にする
+    fn clear_unused_bits(&mut self) {
+        let used = self.len % WORD_BITS;
+        if used != 0
+            && let Some(last) = self.words.last_mut()
+        {
+            *last &= (1u64 << used) - 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let ind: Individual =
+            (0..150).map(|i| (i % 3 == 0) as u8).collect();
+        let packed = PackedIndividual::from_individual(&ind);
+        assert_eq!(packed.words().len(), 3);
+        assert_eq!(packed.to_individual(), ind);
+        assert_eq!(packed.count_ones(), 50);
+    }
+
+    #[test]
+    fn test_set_and_flip() {
+        let mut packed = PackedIndividual::zeros(70);
+        packed.set(65, 1);
+        packed.flip(3);
+        packed.flip(65);
+        assert_eq!(packed.get(3), 1);
+        assert_eq!(packed.get(65), 0);
+        assert_eq!(packed.count_ones(), 1);
+    }
+
+    #[test]
+    fn test_hamming_distance() {
+        let a = PackedIndividual::from_individual(&vec![1, 0, 1, 1, 0]);
+        let b = PackedIndividual::from_individual(&vec![0, 0, 1, 0, 1]);
+        assert_eq!(a.hamming_distance(&b), 3);
+        assert_eq!(a.hamming_distance(&a), 0);
+    }
+
+    #[test]
+    fn test_from_words_clears_unused_bits() {
+        let packed = PackedIndividual::from_words(vec![u64::MAX], 10);
+        assert_eq!(packed.count_ones(), 10);
+    }
+}
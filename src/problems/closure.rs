@@ -0,0 +1,296 @@
+//! クロージャで定義する問題
+//!
+//! 構造体とトレイトの実装を書かずに、変数の範囲・目的関数・制約を
+//! クロージャで与えて問題を作る。
+//! ```ignore
+//! let problem = ClosureProblem::builder()
+//!     .variables(1, -10.0, 10.0)
+//!     .objectives(2)
+//!     .evaluator(|x: &[f64]| vec![x[0] * x[0], (x[0] - 2.0).powi(2)])
+//!     .constraint(|x: &[f64]| 1.0 - x[0])
+//!     .build()?;
+//! ```
+
+use std::fmt;
+
+use crate::individual::Objectives;
+
+use super::{Problem, Sense, VariableBounds};
+
+/// 目的関数値を計算するクロージャ
+type Evaluator<T> = Box<dyn Fn(&[T]) -> Objectives>;
+
+/// 制約 `g(x) <= 0` の `g(x)` を計算するクロージャ
+type Constraint<T> = Box<dyn Fn(&[T]) -> f64>;
+
+/// 問題の構築で発生するエラー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClosureProblemError {
+    /// 変数の範囲が指定されていない
+    MissingBounds,
+    /// 目的関数の数も向きも指定されていない
+    MissingObjectives,
+    /// 目的関数のクロージャが指定されていない
+    MissingEvaluator,
+    /// 下限が上限より大きい変数がある
+    InvalidBounds { index: usize },
+}
+
+impl fmt::Display for ClosureProblemError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingBounds => {
+                write!(f, "variable bounds are not set")
+            }
+            Self::MissingObjectives => {
+                write!(f, "number of objectives is not set")
+            }
+            Self::MissingEvaluator => write!(f, "evaluator is not set"),
+            Self::InvalidBounds { index } => write!(
+                f,
+                "lower bound exceeds upper bound for variable {}",
+                index
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ClosureProblemError {}
+
+/// クロージャで定義した問題
+///
+/// 解は変数の型 `T` のベクトル。実数なら `f64`、ビット列なら `u8` を用いる。
+pub struct ClosureProblem<T> {
+    bounds: Vec<(T, T)>,
+    senses: Vec<Sense>,
+    evaluator: Evaluator<T>,
+    constraints: Vec<Constraint<T>>,
+}
+
+impl<T> ClosureProblem<T> {
+    /// ビルダーを作る
+    pub fn builder() -> ClosureProblemBuilder<T> {
+        ClosureProblemBuilder::default()
+    }
+
+    /// 各変数の `(下限, 上限)`
+    pub fn variable_bounds(&self) -> &[(T, T)] {
+        &self.bounds
+    }
+}
+
+impl<T> fmt::Debug for ClosureProblem<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClosureProblem")
+            .field("number_of_variables", &self.bounds.len())
+            .field("senses", &self.senses)
+            .field("number_of_constraints", &self.constraints.len())
+            .finish()
+    }
+}
+
+impl<T> Problem for ClosureProblem<T> {
+    type Solution = Vec<T>;
+
+    fn number_of_objectives(&self) -> usize {
+        self.senses.len()
+    }
+
+    fn senses(&self) -> Vec<Sense> {
+        self.senses.clone()
+    }
+
+    fn evaluate(&self, x: &Self::Solution) -> Objectives {
+        let objectives = (self.evaluator)(x);
+        debug_assert_eq!(
+            objectives.len(),
+            self.senses.len(),
+            "evaluator returned a wrong number of objectives"
+        );
+        objectives
+    }
+
+    fn number_of_constraints(&self) -> usize {
+        self.constraints.len()
+    }
+
+    /// 各制約について `max(0, g(x))`
+    fn constraint_violation(&self, x: &Self::Solution) -> Vec<f64> {
+        self.constraints.iter().map(|g| g(x).max(0.0)).collect()
+    }
+}
+
+impl VariableBounds for ClosureProblem<f64> {
+    fn bounds(&self) -> Vec<(f64, f64)> {
+        self.bounds.clone()
+    }
+}
+
+/// [`ClosureProblem`] のビルダー
+pub struct ClosureProblemBuilder<T> {
+    bounds: Option<Vec<(T, T)>>,
+    senses: Option<Vec<Sense>>,
+    evaluator: Option<Evaluator<T>>,
+    constraints: Vec<Constraint<T>>,
+}
+
+impl<T> Default for ClosureProblemBuilder<T> {
+    fn default() -> Self {
+        Self {
+            bounds: None,
+            senses: None,
+            evaluator: None,
+            constraints: Vec::new(),
+        }
+    }
+}
+
+impl<T> ClosureProblemBuilder<T> {
+    /// 各変数の `(下限, 上限)` を指定する
+    pub fn bounds(self, bounds: Vec<(T, T)>) -> Self {
+        Self {
+            bounds: Some(bounds),
+            ..self
+        }
+    }
+
+    /// すべて同じ範囲の変数を `n` 個とする
+    pub fn variables(self, n: usize, lower: T, upper: T) -> Self
+    where
+        T: Clone,
+    {
+        self.bounds(vec![(lower, upper); n])
+    }
+
+    /// 目的関数の数を指定する（すべて最小化）
+    pub fn objectives(self, number_of_obj: usize) -> Self {
+        self.senses(vec![Sense::Minimize; number_of_obj])
+    }
+
+    /// 各目的関数の最適化の向きを指定する
+    pub fn senses(self, senses: Vec<Sense>) -> Self {
+        Self {
+            senses: Some(senses),
+            ..self
+        }
+    }
+
+    /// 目的関数値を計算するクロージャを指定する
+    pub fn evaluator<F>(self, evaluator: F) -> Self
+    where
+        F: Fn(&[T]) -> Objectives + 'static,
+    {
+        Self {
+            evaluator: Some(Box::new(evaluator)),
+            ..self
+        }
+    }
+
+    /// 制約 `g(x) <= 0` を追加する
+    ///
+    /// クロージャは `g(x)` を返し、正の値が違反量となる。
+    pub fn constraint<F>(mut self, constraint: F) -> Self
+    where
+        F: Fn(&[T]) -> f64 + 'static,
+    {
+        self.constraints.push(Box::new(constraint));
+        self
+    }
+
+    /// 問題を作る
+    pub fn build(self) -> Result<ClosureProblem<T>, ClosureProblemError>
+    where
+        T: PartialOrd,
+    {
+        let bounds =
+            self.bounds.ok_or(ClosureProblemError::MissingBounds)?;
+        if let Some(index) = bounds.iter().position(|(l, u)| l > u) {
+            return Err(ClosureProblemError::InvalidBounds { index });
+        }
+        let senses = self
+            .senses
+            .filter(|s| !s.is_empty())
+            .ok_or(ClosureProblemError::MissingObjectives)?;
+        let evaluator = self
+            .evaluator
+            .ok_or(ClosureProblemError::MissingEvaluator)?;
+
+        Ok(ClosureProblem {
+            bounds,
+            senses,
+            evaluator,
+            constraints: self.constraints,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_real_problem() {
+        // Schaffer の問題に x >= 1 の制約を加える
+        let problem = ClosureProblem::builder()
+            .variables(1, -10.0, 10.0)
+            .objectives(2)
+            .evaluator(|x: &[f64]| vec![x[0] * x[0], (x[0] - 2.0).powi(2)])
+            .constraint(|x: &[f64]| 1.0 - x[0])
+            .build()
+            .unwrap();
+        assert_eq!(problem.number_of_variables(), 1);
+        assert_eq!(problem.bounds(), vec![(-10.0, 10.0)]);
+        assert_eq!(problem.evaluate(&vec![1.0]), vec![1.0, 1.0]);
+        assert_eq!(problem.number_of_constraints(), 1);
+        assert_eq!(problem.constraint_violation(&vec![1.5]), vec![0.0]);
+        assert_eq!(problem.constraint_violation(&vec![-1.0]), vec![2.0]);
+    }
+
+    #[test]
+    fn test_binary_problem() {
+        // 1の数を最大化し、先頭のビットを最小化する
+        let problem = ClosureProblem::builder()
+            .variables(4, 0u8, 1u8)
+            .senses(vec![Sense::Maximize, Sense::Minimize])
+            .evaluator(|x: &[u8]| {
+                vec![x.iter().map(|&b| b as f64).sum(), x[0] as f64]
+            })
+            .build()
+            .unwrap();
+        let x = vec![1, 0, 1, 1];
+        assert_eq!(problem.evaluate(&x), vec![3.0, 1.0]);
+        assert_eq!(problem.evaluate_minimised(&x), vec![-3.0, 1.0]);
+        assert!(problem.constraint_violation(&x).is_empty());
+    }
+
+    #[test]
+    fn test_build_errors() {
+        let missing = ClosureProblem::<f64>::builder()
+            .variables(2, 0.0, 1.0)
+            .objectives(2)
+            .build();
+        assert_eq!(
+            missing.unwrap_err(),
+            ClosureProblemError::MissingEvaluator
+        );
+
+        let invalid = ClosureProblem::builder()
+            .bounds(vec![(0.0, 1.0), (2.0, 1.0)])
+            .objectives(1)
+            .evaluator(|x: &[f64]| vec![x[0]])
+            .build();
+        assert_eq!(
+            invalid.unwrap_err(),
+            ClosureProblemError::InvalidBounds { index: 1 }
+        );
+
+        let no_objectives = ClosureProblem::builder()
+            .variables(1, 0.0, 1.0)
+            .evaluator(|x: &[f64]| vec![x[0]])
+            .build();
+        assert_eq!(
+            no_objectives.unwrap_err(),
+            ClosureProblemError::MissingObjectives
+        );
+    }
+}
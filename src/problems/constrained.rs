@@ -0,0 +1,508 @@
+//! 制約付きの2目的最小化問題
+//!
+//! SRN, TNK, OSY, BNH と CTP1–7。制約はすべて `c(x) >= 0` の形で定義し、
+//! 違反量 `max(0, -c(x))` を [`Problem::constraint_violation`] で返す。
+
+use std::f64::consts::PI;
+
+use crate::individual::Objectives;
+
+use super::{ParetoFront, Problem, VariableBounds, nondominated_points};
+
+/// `c(x) >= 0` の形の制約の値を違反量に変換する
+fn violations(values: &[f64]) -> Vec<f64> {
+    values.iter().map(|c| (-c).max(0.0)).collect()
+}
+
+/// `[lower, upper]` を `resolution` 個の点で等分割する
+fn linspace(lower: f64, upper: f64, resolution: usize) -> Vec<f64> {
+    (0..resolution)
+        .map(|i| {
+            let t = i as f64 / (resolution.max(2) - 1) as f64;
+            lower + (upper - lower) * t
+        })
+        .collect()
+}
+
+/// Srinivas & Deb の問題
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Srn;
+
+impl Problem for Srn {
+    type Solution = Vec<f64>;
+
+    fn number_of_objectives(&self) -> usize {
+        2
+    }
+
+    fn evaluate(&self, x: &Self::Solution) -> Objectives {
+        vec![
+            2.0 + (x[0] - 2.0).powi(2) + (x[1] - 1.0).powi(2),
+            9.0 * x[0] - (x[1] - 1.0).powi(2),
+        ]
+    }
+
+    fn number_of_constraints(&self) -> usize {
+        2
+    }
+
+    fn constraint_violation(&self, x: &Self::Solution) -> Vec<f64> {
+        violations(&[
+            225.0 - x[0].powi(2) - x[1].powi(2),
+            3.0 * x[1] - x[0] - 10.0,
+        ])
+    }
+}
+
+impl VariableBounds for Srn {
+    fn bounds(&self) -> Vec<(f64, f64)> {
+        vec![(-20.0, 20.0); 2]
+    }
+}
+
+impl ParetoFront for Srn {
+    /// `x1 = -2.5`, `x2 ∈ [2.5, √(225 - 6.25)]`
+    fn pareto_front(&self, resolution: usize) -> Vec<Objectives> {
+        linspace(2.5, (225.0_f64 - 6.25).sqrt(), resolution)
+            .into_iter()
+            .map(|x2| self.evaluate(&vec![-2.5, x2]))
+            .collect()
+    }
+}
+
+/// Tanaka の問題
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Tnk;
+
+impl Tnk {
+    /// 第1制約（波打つ円の外側）の値
+    fn outer(x1: f64, x2: f64) -> f64 {
+        x1.powi(2) + x2.powi(2) - 1.0 - 0.1 * (16.0 * x1.atan2(x2)).cos()
+    }
+}
+
+impl Problem for Tnk {
+    type Solution = Vec<f64>;
+
+    fn number_of_objectives(&self) -> usize {
+        2
+    }
+
+    fn evaluate(&self, x: &Self::Solution) -> Objectives {
+        vec![x[0], x[1]]
+    }
+
+    fn number_of_constraints(&self) -> usize {
+        2
+    }
+
+    fn constraint_violation(&self, x: &Self::Solution) -> Vec<f64> {
+        violations(&[
+            Self::outer(x[0], x[1]),
+            0.5 - (x[0] - 0.5).powi(2) - (x[1] - 0.5).powi(2),
+        ])
+    }
+}
+
+impl VariableBounds for Tnk {
+    fn bounds(&self) -> Vec<(f64, f64)> {
+        vec![(0.0, PI); 2]
+    }
+}
+
+impl ParetoFront for Tnk {
+    /// 第1制約の境界上の点のうち、第2制約を満たす非支配な点
+    fn pareto_front(&self, resolution: usize) -> Vec<Objectives> {
+        let points: Vec<Objectives> = linspace(0.0, PI / 2.0, resolution)
+            .into_iter()
+            .map(|phi| {
+                let r = (1.0 + 0.1 * (16.0 * phi).cos()).sqrt();
+                vec![r * phi.sin(), r * phi.cos()]
+            })
+            .filter(|f| {
+                self.constraint_violation(f).iter().all(|&v| v <= 1e-9)
+            })
+            .collect();
+        nondominated_points(points)
+    }
+}
+
+/// Osyczka & Kundu の問題
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Osy;
+
+impl Problem for Osy {
+    type Solution = Vec<f64>;
+
+    fn number_of_objectives(&self) -> usize {
+        2
+    }
+
+    fn evaluate(&self, x: &Self::Solution) -> Objectives {
+        vec![
+            -(25.0 * (x[0] - 2.0).powi(2)
+                + (x[1] - 2.0).powi(2)
+                + (x[2] - 1.0).powi(2)
+                + (x[3] - 4.0).powi(2)
+                + (x[4] - 1.0).powi(2)),
+            x.iter().map(|v| v * v).sum(),
+        ]
+    }
+
+    fn number_of_constraints(&self) -> usize {
+        6
+    }
+
+    fn constraint_violation(&self, x: &Self::Solution) -> Vec<f64> {
+        violations(&[
+            x[0] + x[1] - 2.0,
+            6.0 - x[0] - x[1],
+            2.0 - x[1] + x[0],
+            2.0 - x[0] + 3.0 * x[1],
+            4.0 - (x[2] - 3.0).powi(2) - x[3],
+            (x[4] - 3.0).powi(2) + x[5] - 4.0,
+        ])
+    }
+}
+
+impl VariableBounds for Osy {
+    fn bounds(&self) -> Vec<(f64, f64)> {
+        vec![
+            (0.0, 10.0),
+            (0.0, 10.0),
+            (1.0, 5.0),
+            (0.0, 6.0),
+            (1.0, 5.0),
+            (0.0, 10.0),
+        ]
+    }
+}
+
+impl ParetoFront for Osy {
+    /// Deb による5つの区間（`x4 = x6 = 0`）上の点のうち非支配なもの
+    fn pareto_front(&self, resolution: usize) -> Vec<Objectives> {
+        let n = resolution.div_ceil(5).max(2);
+        let segments: Vec<Vec<f64>> = [
+            linspace(1.0, 5.0, n)
+                .into_iter()
+                .map(|x3| vec![5.0, 1.0, x3, 0.0, 5.0, 0.0])
+                .collect::<Vec<_>>(),
+            linspace(1.0, 5.0, n)
+                .into_iter()
+                .map(|x3| vec![5.0, 1.0, x3, 0.0, 1.0, 0.0])
+                .collect(),
+            linspace(4.056, 5.0, n)
+                .into_iter()
+                .map(|x1| vec![x1, (x1 - 2.0) / 3.0, 1.0, 0.0, 1.0, 0.0])
+                .collect(),
+            linspace(1.0, 3.732, n)
+                .into_iter()
+                .map(|x3| vec![0.0, 2.0, x3, 0.0, 1.0, 0.0])
+                .collect(),
+            linspace(0.0, 1.0, n)
+                .into_iter()
+                .map(|x1| vec![x1, 2.0 - x1, 1.0, 0.0, 1.0, 0.0])
+                .collect(),
+        ]
+        .concat();
+
+        let points = segments
+            .iter()
+            .filter(|x| {
+                self.constraint_violation(x).iter().all(|&v| v <= 1e-9)
+            })
+            .map(|x| self.evaluate(x))
+            .collect();
+        nondominated_points(points)
+    }
+}
+
+/// Binh & Korn の問題
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Bnh;
+
+impl Problem for Bnh {
+    type Solution = Vec<f64>;
+
+    fn number_of_objectives(&self) -> usize {
+        2
+    }
+
+    fn evaluate(&self, x: &Self::Solution) -> Objectives {
+        vec![
+            4.0 * x[0].powi(2) + 4.0 * x[1].powi(2),
+            (x[0] - 5.0).powi(2) + (x[1] - 5.0).powi(2),
+        ]
+    }
+
+    fn number_of_constraints(&self) -> usize {
+        2
+    }
+
+    fn constraint_violation(&self, x: &Self::Solution) -> Vec<f64> {
+        violations(&[
+            25.0 - (x[0] - 5.0).powi(2) - x[1].powi(2),
+            (x[0] - 8.0).powi(2) + (x[1] + 3.0).powi(2) - 7.7,
+        ])
+    }
+}
+
+impl VariableBounds for Bnh {
+    fn bounds(&self) -> Vec<(f64, f64)> {
+        vec![(0.0, 5.0), (0.0, 3.0)]
+    }
+}
+
+impl ParetoFront for Bnh {
+    /// `x1 = x2 ∈ [0, 3]` と `x1 ∈ [3, 5], x2 = 3`
+    fn pareto_front(&self, resolution: usize) -> Vec<Objectives> {
+        let n = resolution.div_ceil(2).max(2);
+        let diagonal =
+            linspace(0.0, 3.0, n).into_iter().map(|v| vec![v, v]);
+        let edge = linspace(3.0, 5.0, n)
+            .into_iter()
+            .skip(1)
+            .map(|v| vec![v, 3.0]);
+        diagonal.chain(edge).map(|x| self.evaluate(&x)).collect()
+    }
+}
+
+/// CTP問題の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CtpKind {
+    Ctp1,
+    Ctp2,
+    Ctp3,
+    Ctp4,
+    Ctp5,
+    Ctp6,
+    Ctp7,
+}
+
+impl CtpKind {
+    /// CTP2–7 の制約のパラメータ `(θ, a, b, c, d, e)`
+    ///
+    /// CTP1 は別の形の制約（[`CTP1_PARAMETERS`]）を持つため `None` を返す。
+    fn parameters(&self) -> Option<(f64, f64, f64, f64, f64, f64)> {
+        match self {
+            Self::Ctp1 => None,
+            Self::Ctp2 => Some((-0.2 * PI, 0.2, 10.0, 1.0, 6.0, 1.0)),
+            Self::Ctp3 => Some((-0.2 * PI, 0.1, 10.0, 1.0, 0.5, 1.0)),
+            Self::Ctp4 => Some((-0.2 * PI, 0.75, 10.0, 1.0, 0.5, 1.0)),
+            Self::Ctp5 => Some((-0.2 * PI, 0.1, 10.0, 2.0, 0.5, 1.0)),
+            Self::Ctp6 => Some((0.1 * PI, 40.0, 0.5, 1.0, 2.0, -2.0)),
+            Self::Ctp7 => Some((-0.05 * PI, 40.0, 5.0, 1.0, 6.0, 0.0)),
+        }
+    }
+}
+
+/// CTP1 の制約のパラメータ `(a_j, b_j)`
+const CTP1_PARAMETERS: [(f64, f64); 2] = [(0.858, 0.541), (0.728, 0.295)];
+
+/// フロントを求めるときの第2目的関数の刻み幅
+const CTP_SCAN_STEP: f64 = 1e-3;
+
+/// Deb, Pratap, Meyarivan による制約付き問題
+///
+/// 距離関数は `g = 1 + Σ_{i>=2} x_i`（`x_i ∈ [0, 1]`）とし、`g = 1` で
+/// 制約のない場合のフロントとなる。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ctp {
+    pub kind: CtpKind,
+    pub number_of_variables: usize,
+}
+
+impl Ctp {
+    /// 変数の数を指定して問題を作る（2以上）
+    pub fn new(kind: CtpKind, number_of_variables: usize) -> Self {
+        assert!(
+            number_of_variables >= 2,
+            "CTP needs at least 2 variables"
+        );
+        Self {
+            kind,
+            number_of_variables,
+        }
+    }
+
+    /// 目的関数値から制約の値を求める
+    fn constraint_values(&self, f: &[f64]) -> Vec<f64> {
+        let (f1, f2) = (f[0], f[1]);
+        let Some((theta, a, b, c, d, e)) = self.kind.parameters() else {
+            return CTP1_PARAMETERS
+                .iter()
+                .map(|(a, b)| f2 - a * (-b * f1).exp())
+                .collect();
+        };
+
+        let lhs = theta.cos() * (f2 - e) - theta.sin() * f1;
+        let inner = theta.sin() * (f2 - e) + theta.cos() * f1;
+        let rhs = a * (b * PI * inner.powf(c)).sin().abs().powf(d);
+        vec![lhs - rhs]
+    }
+
+    /// `g = 1` のときの第2目的関数の最小値
+    fn lower_f2(&self, f1: f64) -> f64 {
+        match self.kind {
+            CtpKind::Ctp1 => (-f1).exp(),
+            _ => 1.0 - f1,
+        }
+    }
+}
+
+impl Problem for Ctp {
+    type Solution = Vec<f64>;
+
+    fn number_of_objectives(&self) -> usize {
+        2
+    }
+
+    fn evaluate(&self, x: &Self::Solution) -> Objectives {
+        let f1 = x[0];
+        let g = 1.0 + x[1..].iter().sum::<f64>();
+        let f2 = match self.kind {
+            CtpKind::Ctp1 => g * (-f1 / g).exp(),
+            _ => g * (1.0 - f1 / g),
+        };
+        vec![f1, f2]
+    }
+
+    fn number_of_constraints(&self) -> usize {
+        match self.kind {
+            CtpKind::Ctp1 => CTP1_PARAMETERS.len(),
+            _ => 1,
+        }
+    }
+
+    fn constraint_violation(&self, x: &Self::Solution) -> Vec<f64> {
+        violations(&self.constraint_values(&self.evaluate(x)))
+    }
+}
+
+impl VariableBounds for Ctp {
+    fn bounds(&self) -> Vec<(f64, f64)> {
+        vec![(0.0, 1.0); self.number_of_variables]
+    }
+}
+
+impl ParetoFront for Ctp {
+    /// 第1目的関数の各値について、実行可能な第2目的関数の最小値を求める
+    ///
+    /// 第2目的関数は `g = 1` の値から刻み幅 `10⁻³` で増やして探すため、
+    /// 制約の境界上にフロントがある問題（CTP1, 3–6）では近似となる。
+    fn pareto_front(&self, resolution: usize) -> Vec<Objectives> {
+        let max_g = self.number_of_variables as f64;
+        let steps = (max_g / CTP_SCAN_STEP) as usize;
+        let points: Vec<Objectives> = linspace(0.0, 1.0, resolution)
+            .into_iter()
+            .filter_map(|f1| {
+                let lower = self.lower_f2(f1);
+                (0..=steps)
+                    .map(|j| vec![f1, lower + j as f64 * CTP_SCAN_STEP])
+                    .find(|f| {
+                        self.constraint_values(f).iter().all(|&c| c >= 0.0)
+                    })
+            })
+            .collect();
+        nondominated_points(points)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_feasible<P: Problem>(problem: &P, x: &P::Solution) -> bool {
+        problem.constraint_violation(x).iter().all(|&v| v == 0.0)
+    }
+
+    #[test]
+    fn test_srn() {
+        let problem = Srn;
+        assert!(is_feasible(&problem, &vec![-2.5, 5.0]));
+        // x1² + x2² = 400 > 225、x1 - 3x2 + 10 = 30 > 0
+        assert_eq!(
+            problem.constraint_violation(&vec![20.0, 0.0]),
+            vec![175.0, 30.0]
+        );
+        let front = problem.pareto_front(50);
+        assert_eq!(front.len(), 50);
+        assert_eq!(nondominated_points(front.clone()), front);
+    }
+
+    #[test]
+    fn test_tnk_front_is_feasible() {
+        let problem = Tnk;
+        let front = problem.pareto_front(500);
+        assert!(!front.is_empty());
+        front.iter().for_each(|f| {
+            // 境界上の点のため、丸め誤差程度の違反は許容する
+            assert!(
+                problem.constraint_violation(f).iter().all(|&v| v < 1e-9)
+            );
+        });
+    }
+
+    #[test]
+    fn test_osy() {
+        let problem = Osy;
+        let x = vec![5.0, 1.0, 5.0, 0.0, 5.0, 0.0];
+        assert!(is_feasible(&problem, &x));
+        assert_eq!(problem.evaluate(&x), vec![-274.0, 76.0]);
+        let front = problem.pareto_front(100);
+        assert!(front.contains(&vec![-274.0, 76.0]));
+    }
+
+    #[test]
+    fn test_bnh() {
+        let problem = Bnh;
+        assert_eq!(problem.evaluate(&vec![0.0, 0.0]), vec![0.0, 50.0]);
+        assert_eq!(
+            problem.constraint_violation(&vec![0.0, 0.0]),
+            vec![0.0, 0.0]
+        );
+        let front = problem.pareto_front(100);
+        assert_eq!(front[0], vec![0.0, 50.0]);
+        assert_eq!(front[front.len() - 1], vec![136.0, 4.0]);
+        assert_eq!(nondominated_points(front.clone()), front);
+    }
+
+    #[test]
+    fn test_ctp_fronts_are_feasible() {
+        let kinds = [
+            CtpKind::Ctp1,
+            CtpKind::Ctp2,
+            CtpKind::Ctp3,
+            CtpKind::Ctp4,
+            CtpKind::Ctp5,
+            CtpKind::Ctp6,
+            CtpKind::Ctp7,
+        ];
+        kinds.iter().for_each(|&kind| {
+            let problem = Ctp::new(kind, 2);
+            let front = problem.pareto_front(200);
+            assert!(!front.is_empty(), "{:?}", kind);
+            front.iter().for_each(|f| {
+                assert!(
+                    problem.constraint_values(f).iter().all(|&c| c >= 0.0),
+                    "{:?}",
+                    kind
+                );
+            });
+        });
+    }
+
+    #[test]
+    fn test_ctp_constraint_violation() {
+        // CTP7: 制約のない最適解の一部は実行不可能
+        let problem = Ctp::new(CtpKind::Ctp7, 2);
+        let violations: Vec<bool> = linspace(0.0, 1.0, 50)
+            .into_iter()
+            .map(|x1| !is_feasible(&problem, &vec![x1, 0.0]))
+            .collect();
+        assert!(violations.iter().any(|&v| v));
+        assert!(violations.iter().any(|&v| !v));
+        assert_eq!(problem.number_of_constraints(), 1);
+        assert_eq!(Ctp::new(CtpKind::Ctp1, 2).number_of_constraints(), 2);
+    }
+}
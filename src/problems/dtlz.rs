@@ -0,0 +1,366 @@
+//! DTLZ問題群
+//!
+//! Deb, Thiele, Laumanns, Zitzler (2002) による目的関数の数を変えられる
+//! 最小化問題。変数の数は `目的関数の数 + k - 1` で、すべて `[0, 1]` の範囲をとる。
+
+use std::f64::consts::PI;
+
+use crate::individual::Objectives;
+
+use super::{
+    ParetoFront, Problem, VariableBounds, das_dennis_points,
+    nondominated_points,
+};
+
+/// DTLZ問題の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DtlzKind {
+    Dtlz1,
+    Dtlz2,
+    Dtlz3,
+    Dtlz4,
+    Dtlz5,
+    Dtlz6,
+    Dtlz7,
+}
+
+impl DtlzKind {
+    /// 論文で推奨されている距離変数の数 `k`
+    pub fn default_k(&self) -> usize {
+        match self {
+            Self::Dtlz1 => 5,
+            Self::Dtlz7 => 20,
+            _ => 10,
+        }
+    }
+}
+
+/// DTLZ4 の変数の変換 `x^α` の指数
+const DTLZ4_ALPHA: i32 = 100;
+
+/// DTLZ問題
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Dtlz {
+    pub kind: DtlzKind,
+    pub number_of_obj: usize,
+    pub number_of_variables: usize,
+}
+
+impl Dtlz {
+    /// 推奨される変数の数で問題を作る
+    pub fn new(kind: DtlzKind, number_of_obj: usize) -> Self {
+        Self::with_variables(
+            kind,
+            number_of_obj,
+            number_of_obj + kind.default_k() - 1,
+        )
+    }
+
+    /// 変数の数を指定して問題を作る
+    ///
+    /// 目的関数の数は2以上、変数の数は目的関数の数以上とする。
+    pub fn with_variables(
+        kind: DtlzKind,
+        number_of_obj: usize,
+        number_of_variables: usize,
+    ) -> Self {
+        assert!(number_of_obj >= 2, "DTLZ needs at least 2 objectives");
+        assert!(
+            number_of_variables >= number_of_obj,
+            "DTLZ needs at least as many variables as objectives"
+        );
+        Self {
+            kind,
+            number_of_obj,
+            number_of_variables,
+        }
+    }
+
+    /// 距離変数の数 `k`
+    pub fn k(&self) -> usize {
+        self.number_of_variables - self.number_of_obj + 1
+    }
+
+    /// 距離関数 `g`（パレート最適解で最小値をとる。DTLZ7 は1、それ以外は0）
+    fn g(&self, distance: &[f64]) -> f64 {
+        match self.kind {
+            DtlzKind::Dtlz1 | DtlzKind::Dtlz3 => {
+                100.0
+                    * (distance.len() as f64
+                        + distance
+                            .iter()
+                            .map(|x| {
+                                (x - 0.5).powi(2)
+                                    - (20.0 * PI * (x - 0.5)).cos()
+                            })
+                            .sum::<f64>())
+            }
+            DtlzKind::Dtlz2 | DtlzKind::Dtlz4 | DtlzKind::Dtlz5 => {
+                distance.iter().map(|x| (x - 0.5).powi(2)).sum()
+            }
+            DtlzKind::Dtlz6 => distance.iter().map(|x| x.powf(0.1)).sum(),
+            DtlzKind::Dtlz7 => {
+                1.0 + 9.0 * distance.iter().sum::<f64>()
+                    / distance.len() as f64
+            }
+        }
+    }
+
+    /// 線形なフロント（DTLZ1）上の点
+    fn linear(position: &[f64], scale: f64) -> Objectives {
+        let m = position.len() + 1;
+        (0..m)
+            .map(|i| {
+                let prod: f64 = position[..m - 1 - i].iter().product();
+                let last = if i == 0 {
+                    1.0
+                } else {
+                    1.0 - position[m - 1 - i]
+                };
+                scale * prod * last
+            })
+            .collect()
+    }
+
+    /// 球面のフロント（DTLZ2–6）上の点
+    ///
+    /// `theta` は `[0, π/2]` の角度。
+    fn spherical(theta: &[f64], scale: f64) -> Objectives {
+        let m = theta.len() + 1;
+        (0..m)
+            .map(|i| {
+                let prod: f64 =
+                    theta[..m - 1 - i].iter().map(|t| t.cos()).product();
+                let last =
+                    if i == 0 { 1.0 } else { theta[m - 1 - i].sin() };
+                scale * prod * last
+            })
+            .collect()
+    }
+
+    /// DTLZ7 の最後の目的関数
+    fn dtlz7_last(f: &[f64], g: f64) -> f64 {
+        let h = f.len() as f64 + 1.0
+            - f.iter()
+                .map(|fi| fi / (1.0 + g) * (1.0 + (3.0 * PI * fi).sin()))
+                .sum::<f64>();
+        (1.0 + g) * h
+    }
+}
+
+impl Problem for Dtlz {
+    type Solution = Vec<f64>;
+
+    fn number_of_objectives(&self) -> usize {
+        self.number_of_obj
+    }
+
+    fn evaluate(&self, x: &Self::Solution) -> Objectives {
+        let (position, distance) = x.split_at(self.number_of_obj - 1);
+        let g = self.g(distance);
+
+        match self.kind {
+            DtlzKind::Dtlz1 => Self::linear(position, 0.5 * (1.0 + g)),
+            DtlzKind::Dtlz2 | DtlzKind::Dtlz3 => {
+                let theta: Vec<f64> =
+                    position.iter().map(|x| x * PI / 2.0).collect();
+                Self::spherical(&theta, 1.0 + g)
+            }
+            DtlzKind::Dtlz4 => {
+                let theta: Vec<f64> = position
+                    .iter()
+                    .map(|x| x.powi(DTLZ4_ALPHA) * PI / 2.0)
+                    .collect();
+                Self::spherical(&theta, 1.0 + g)
+            }
+            DtlzKind::Dtlz5 | DtlzKind::Dtlz6 => {
+                let theta: Vec<f64> = position
+                    .iter()
+                    .enumerate()
+                    .map(|(i, x)| {
+                        if i == 0 {
+                            x * PI / 2.0
+                        } else {
+                            PI / (4.0 * (1.0 + g)) * (1.0 + 2.0 * g * x)
+                        }
+                    })
+                    .collect();
+                Self::spherical(&theta, 1.0 + g)
+            }
+            DtlzKind::Dtlz7 => {
+                let mut f = position.to_vec();
+                f.push(Self::dtlz7_last(position, g));
+                f
+            }
+        }
+    }
+}
+
+impl VariableBounds for Dtlz {
+    fn bounds(&self) -> Vec<(f64, f64)> {
+        vec![(0.0, 1.0); self.number_of_variables]
+    }
+}
+
+/// 点の数が `resolution` を超えない最大の Das–Dennis の分割数
+fn divisions_for(number_of_obj: usize, resolution: usize) -> usize {
+    (1..)
+        .take_while(|&h| {
+            das_dennis_count(number_of_obj, h) <= resolution.max(1)
+        })
+        .last()
+        .unwrap_or(1)
+}
+
+/// Das–Dennis の点の数 `C(H + M - 1, M - 1)`
+fn das_dennis_count(number_of_obj: usize, divisions: usize) -> usize {
+    (1..number_of_obj).fold(1, |acc, i| acc * (divisions + i) / i)
+}
+
+impl ParetoFront for Dtlz {
+    /// - DTLZ1: 単体 `Σf = 0.5` 上の Das–Dennis の点
+    /// - DTLZ2–4: Das–Dennis の点を単位球面に射影した点
+    /// - DTLZ5, 6: 第1角度を等分割した曲線上の点
+    /// - DTLZ7: 位置変数の格子点のうち非支配なもの
+    fn pareto_front(&self, resolution: usize) -> Vec<Objectives> {
+        let m = self.number_of_obj;
+        match self.kind {
+            DtlzKind::Dtlz1 => {
+                das_dennis_points(m, divisions_for(m, resolution))
+                    .into_iter()
+                    .map(|w| w.iter().map(|v| 0.5 * v).collect())
+                    .collect()
+            }
+            DtlzKind::Dtlz2 | DtlzKind::Dtlz3 | DtlzKind::Dtlz4 => {
+                das_dennis_points(m, divisions_for(m, resolution))
+                    .into_iter()
+                    .map(|w| {
+                        let norm =
+                            w.iter().map(|v| v * v).sum::<f64>().sqrt();
+                        w.iter().map(|v| v / norm).collect()
+                    })
+                    .collect()
+            }
+            DtlzKind::Dtlz5 | DtlzKind::Dtlz6 => (0..resolution)
+                .map(|i| {
+                    let t = i as f64 / (resolution.max(2) - 1) as f64;
+                    let theta: Vec<f64> = std::iter::once(t * PI / 2.0)
+                        .chain(std::iter::repeat_n(PI / 4.0, m - 2))
+                        .collect();
+                    Self::spherical(&theta, 1.0)
+                })
+                .collect(),
+            DtlzKind::Dtlz7 => {
+                // 各位置変数の分割数 d（d^(M-1) <= resolution）
+                let d = (1..)
+                    .take_while(|d: &usize| {
+                        d.pow((m - 1) as u32) <= resolution.max(1)
+                    })
+                    .last()
+                    .unwrap_or(1)
+                    .max(2);
+                let points: Vec<Objectives> = (0..d.pow((m - 1) as u32))
+                    .map(|index| {
+                        let mut f: Vec<f64> = (0..m - 1)
+                            .map(|j| {
+                                let digit = index / d.pow(j as u32) % d;
+                                digit as f64 / (d - 1) as f64
+                            })
+                            .collect();
+                        f.push(Self::dtlz7_last(&f, 1.0));
+                        f
+                    })
+                    .collect();
+                nondominated_points(points)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [DtlzKind; 7] = [
+        DtlzKind::Dtlz1,
+        DtlzKind::Dtlz2,
+        DtlzKind::Dtlz3,
+        DtlzKind::Dtlz4,
+        DtlzKind::Dtlz5,
+        DtlzKind::Dtlz6,
+        DtlzKind::Dtlz7,
+    ];
+
+    /// 距離変数を最適値にした解
+    fn optimal_solution(problem: &Dtlz, position: f64) -> Vec<f64> {
+        let distance = match problem.kind {
+            DtlzKind::Dtlz6 | DtlzKind::Dtlz7 => 0.0,
+            _ => 0.5,
+        };
+        let mut x = vec![distance; problem.number_of_variables];
+        x[..problem.number_of_obj - 1].fill(position);
+        x
+    }
+
+    #[test]
+    fn test_dimensions() {
+        ALL.iter().for_each(|&kind| {
+            let problem = Dtlz::new(kind, 5);
+            assert_eq!(problem.k(), kind.default_k());
+            assert_eq!(
+                problem.bounds().len(),
+                problem.number_of_variables
+            );
+            let x = optimal_solution(&problem, 0.3);
+            assert_eq!(problem.evaluate(&x).len(), 5);
+        });
+    }
+
+    #[test]
+    fn test_optimal_solutions_lie_on_front() {
+        // DTLZ1 は Σf = 0.5、DTLZ2–6 は Σf² = 1 を満たす
+        ALL[..6].iter().for_each(|&kind| {
+            let problem = Dtlz::new(kind, 3);
+            let f = problem.evaluate(&optimal_solution(&problem, 0.7));
+            let value: f64 = match kind {
+                DtlzKind::Dtlz1 => f.iter().sum(),
+                _ => f.iter().map(|v| v * v).sum(),
+            };
+            let expected = if kind == DtlzKind::Dtlz1 { 0.5 } else { 1.0 };
+            assert!((value - expected).abs() < 1e-9, "{:?}", kind);
+        });
+    }
+
+    #[test]
+    fn test_dtlz7_optimal_solution() {
+        // 位置変数がすべて0のとき、最後の目的関数は 2M
+        let problem = Dtlz::new(DtlzKind::Dtlz7, 3);
+        let f = problem.evaluate(&optimal_solution(&problem, 0.0));
+        assert_eq!(f, vec![0.0, 0.0, 6.0]);
+    }
+
+    #[test]
+    fn test_pareto_front() {
+        // 3目的で91点以下 → 分割数12の91点
+        let front = Dtlz::new(DtlzKind::Dtlz1, 3).pareto_front(100);
+        assert_eq!(front.len(), 91);
+        front.iter().for_each(|f| {
+            assert!((f.iter().sum::<f64>() - 0.5).abs() < 1e-9);
+        });
+
+        let front = Dtlz::new(DtlzKind::Dtlz2, 3).pareto_front(100);
+        front.iter().for_each(|f| {
+            assert!(
+                (f.iter().map(|v| v * v).sum::<f64>() - 1.0).abs() < 1e-9
+            );
+        });
+
+        // DTLZ7 のフロントは最適解の目的関数値と一致する
+        let problem = Dtlz::new(DtlzKind::Dtlz7, 3);
+        let front = problem.pareto_front(400);
+        assert!(!front.is_empty() && front.len() < 400);
+        assert!(front.contains(
+            &problem.evaluate(&optimal_solution(&problem, 0.0))
+        ));
+    }
+}
@@ -0,0 +1,192 @@
+//! 2目的の非容量制約付き施設配置問題
+//!
+//! 開設する施設を選び、各顧客を開設した施設のうち最もコストの小さいものに
+//! 割り当てる。開設コストの合計と、割り当てのコスト（サービス距離）の合計を
+//! 最小化する。少なくとも1つの施設を開設することを制約とする。
+
+mod orlib;
+
+use crate::individual::{Individual, Objectives};
+
+use super::Problem;
+
+/// 非容量制約付き施設配置問題
+#[derive(Debug, Clone, PartialEq)]
+pub struct FacilityLocation {
+    /// 各施設の開設コスト
+    pub opening_costs: Vec<f64>,
+    /// 割り当てのコスト `service_costs[顧客][施設]`
+    pub service_costs: Vec<Vec<f64>>,
+}
+
+impl FacilityLocation {
+    /// 開設コストと割り当てのコストから問題を作る
+    pub fn new(
+        opening_costs: Vec<f64>,
+        service_costs: Vec<Vec<f64>>,
+    ) -> Self {
+        assert!(
+            service_costs
+                .iter()
+                .all(|row| row.len() == opening_costs.len()),
+            "service costs must be given for every facility"
+        );
+        Self {
+            opening_costs,
+            service_costs,
+        }
+    }
+
+    /// 施設の数
+    pub fn number_of_facilities(&self) -> usize {
+        self.opening_costs.len()
+    }
+
+    /// 顧客の数
+    pub fn number_of_customers(&self) -> usize {
+        self.service_costs.len()
+    }
+
+    /// 開設した施設の開設コストの合計
+    pub fn opening_cost(&self, x: &Individual) -> f64 {
+        x.iter()
+            .zip(&self.opening_costs)
+            .filter(|(b, _)| **b == 1)
+            .map(|(_, c)| c)
+            .sum()
+    }
+
+    /// 各顧客を割り当てる施設（開設した施設がない場合は `None`）
+    pub fn assignment(&self, x: &Individual) -> Option<Vec<usize>> {
+        self.service_costs
+            .iter()
+            .map(|row| {
+                (0..self.number_of_facilities())
+                    .filter(|&i| x[i] == 1)
+                    .min_by(|&a, &b| row[a].total_cmp(&row[b]))
+            })
+            .collect()
+    }
+
+    /// 割り当てのコストの合計
+    ///
+    /// 開設した施設がない場合は0とし、制約違反として扱う。
+    pub fn service_cost(&self, x: &Individual) -> f64 {
+        self.assignment(x)
+            .map(|assignment| {
+                assignment
+                    .iter()
+                    .zip(&self.service_costs)
+                    .map(|(&i, row)| row[i])
+                    .sum()
+            })
+            .unwrap_or(0.0)
+    }
+}
+
+impl Problem for FacilityLocation {
+    type Solution = Individual;
+
+    fn number_of_objectives(&self) -> usize {
+        2
+    }
+
+    fn evaluate(&self, x: &Self::Solution) -> Objectives {
+        vec![self.opening_cost(x), self.service_cost(x)]
+    }
+
+    fn number_of_constraints(&self) -> usize {
+        1
+    }
+
+    /// 開設した施設がない場合は1
+    fn constraint_violation(&self, x: &Self::Solution) -> Vec<f64> {
+        let opened = x.contains(&1);
+        vec![if opened { 0.0 } else { 1.0 }]
+    }
+}
+
+/// 実行不可能解を修復する
+///
+/// 開設した施設がない場合は、その施設だけを開設したときの開設コストと
+/// 割り当てのコストの合計が最も小さい施設を開設する。その後、どの顧客も
+/// 割り当てられていない施設を閉鎖する（どちらの目的関数も悪化しない）。
+pub fn repair_facility_location(
+    problem: &FacilityLocation,
+    x: &Individual,
+) -> Individual {
+    let mut repaired = x.clone();
+    if !repaired.contains(&1) {
+        let total = |i: usize| {
+            problem.opening_costs[i]
+                + problem
+                    .service_costs
+                    .iter()
+                    .map(|row| row[i])
+                    .sum::<f64>()
+        };
+        if let Some(best) = (0..problem.number_of_facilities())
+            .min_by(|&a, &b| total(a).total_cmp(&total(b)))
+        {
+            repaired[best] = 1;
+        }
+    }
+
+    if let Some(assignment) = problem.assignment(&repaired) {
+        let mut used = vec![false; problem.number_of_facilities()];
+        assignment.iter().for_each(|&i| used[i] = true);
+        // 顧客がいない場合は開設した施設をすべて残す
+        if used.iter().any(|&u| u) {
+            repaired
+                .iter_mut()
+                .zip(&used)
+                .for_each(|(b, &u)| *b &= u as u8);
+        }
+    }
+
+    repaired
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_problem() -> FacilityLocation {
+        FacilityLocation::new(
+            vec![10.0, 20.0, 5.0],
+            vec![
+                vec![1.0, 4.0, 9.0],
+                vec![2.0, 3.0, 9.0],
+                vec![6.0, 1.5, 9.0],
+            ],
+        )
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let problem = create_problem();
+        let x = vec![1, 1, 0];
+        assert_eq!(problem.assignment(&x), Some(vec![0, 0, 1]));
+        assert_eq!(problem.evaluate(&x), vec![30.0, 4.5]);
+        assert_eq!(problem.constraint_violation(&x), vec![0.0]);
+
+        let closed = vec![0, 0, 0];
+        assert_eq!(problem.assignment(&closed), None);
+        assert_eq!(problem.constraint_violation(&closed), vec![1.0]);
+    }
+
+    #[test]
+    fn test_repair() {
+        let problem = create_problem();
+        // 単独で開設したときの合計: [19.0, 28.5, 32.0]
+        assert_eq!(
+            repair_facility_location(&problem, &vec![0, 0, 0]),
+            vec![1, 0, 0]
+        );
+        // 施設2には誰も割り当てられないため閉鎖する
+        assert_eq!(
+            repair_facility_location(&problem, &vec![1, 1, 1]),
+            vec![1, 1, 0]
+        );
+    }
+}
@@ -0,0 +1,99 @@
+//! OR-Library 形式（cap71 など）のインスタンスの読み込み
+//!
+//! ```text
+//! 16 50
+//! 5000 7500.0
+//! ...
+//! 146
+//! 6739.725 10355.05 ...
+//! ```
+//! 施設の数 `m` と顧客の数 `n`、施設ごとに容量と開設コスト、顧客ごとに需要と
+//! 各施設へ需要をすべて割り当てたときのコスト（`m` 個）を並べる。
+//! 容量と需要は無視する（容量の値が `capacity` という文字列でもよい）。
+//! 値は空白で区切られていればよく、改行の位置は問わない。
+
+use std::path::Path;
+
+use crate::problems::parse::{
+    LoadError, next_token, next_value, read_file, tokens,
+};
+
+use super::FacilityLocation;
+
+impl FacilityLocation {
+    /// OR-Library 形式のファイルから問題を読み込む
+    pub fn from_orlib_file(path: &Path) -> Result<Self, LoadError> {
+        Self::from_orlib_str(&read_file(path)?)
+    }
+
+    /// OR-Library 形式の文字列から問題を読み込む
+    pub fn from_orlib_str(content: &str) -> Result<Self, LoadError> {
+        let mut tokens = tokens(content);
+
+        let m: usize = next_value(&mut tokens, "number of facilities")?;
+        let n: usize = next_value(&mut tokens, "number of customers")?;
+
+        let opening_costs = (0..m)
+            .map(|_| {
+                next_token(&mut tokens, "capacity")?;
+                next_value(&mut tokens, "opening cost")
+            })
+            .collect::<Result<Vec<f64>, _>>()?;
+
+        let service_costs = (0..n)
+            .map(|_| {
+                next_token(&mut tokens, "demand")?;
+                (0..m)
+                    .map(|_| next_value(&mut tokens, "service cost"))
+                    .collect::<Result<Vec<f64>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self::new(opening_costs, service_costs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTENT: &str = "2 3
+ capacity 10.0
+ capacity 20.0
+ 5
+ 1.0 4.0
+ 5
+ 2.0
+ 3.0
+ 5
+ 6.0 1.5
+";
+
+    #[test]
+    fn test_from_orlib_str() {
+        let problem = FacilityLocation::from_orlib_str(CONTENT).unwrap();
+        assert_eq!(problem.opening_costs, vec![10.0, 20.0]);
+        assert_eq!(
+            problem.service_costs,
+            vec![vec![1.0, 4.0], vec![2.0, 3.0], vec![6.0, 1.5]]
+        );
+    }
+
+    #[test]
+    fn test_from_orlib_str_errors() {
+        let truncated = CONTENT.replace(" 6.0 1.5\n", " 6.0\n");
+        assert!(matches!(
+            FacilityLocation::from_orlib_str(&truncated),
+            Err(LoadError::UnexpectedEof {
+                field: "service cost"
+            })
+        ));
+        let broken = CONTENT.replace("20.0", "x");
+        match FacilityLocation::from_orlib_str(&broken) {
+            Err(LoadError::Syntax { line, .. }) => {
+                assert_eq!(line, 3)
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,179 @@
+//! 多目的の順列フローショップスケジューリング問題
+//!
+//! すべてのジョブを同じ機械の順で処理し、ジョブの処理順を解とする。
+//! メイクスパン、総納期遅れ、総フロー時間の3つを最小化する。
+//!
+//! 処理順の生成や交叉・突然変異には、順列を保つ
+//! [`crate::operators::random_permutation`] や
+//! [`crate::operators::order_crossover_random`] などを使う。
+
+mod taillard;
+
+use crate::individual::Objectives;
+
+use super::{Problem, permutation_violation};
+
+/// ジョブの処理順（`0..n` の順列）
+pub type JobSequence = Vec<usize>;
+
+/// 順列フローショップスケジューリング問題
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlowShop {
+    /// 処理時間 `processing_times[機械][ジョブ]`
+    pub processing_times: Vec<Vec<u64>>,
+    /// 各ジョブの納期
+    pub due_dates: Vec<u64>,
+}
+
+impl FlowShop {
+    /// 処理時間と納期から問題を作る
+    pub fn new(
+        processing_times: Vec<Vec<u64>>,
+        due_dates: Vec<u64>,
+    ) -> Self {
+        assert!(!processing_times.is_empty(), "at least one machine");
+        assert!(
+            processing_times
+                .iter()
+                .all(|row| row.len() == due_dates.len()),
+            "processing times must be given for every job"
+        );
+        Self {
+            processing_times,
+            due_dates,
+        }
+    }
+
+    /// ジョブの数
+    pub fn number_of_jobs(&self) -> usize {
+        self.due_dates.len()
+    }
+
+    /// 機械の数
+    pub fn number_of_machines(&self) -> usize {
+        self.processing_times.len()
+    }
+
+    /// `sequence` がすべてのジョブをちょうど1回ずつ含む場合true
+    pub fn is_valid_sequence(&self, sequence: &[usize]) -> bool {
+        permutation_violation(sequence, self.number_of_jobs()) == 0
+    }
+
+    /// 処理順に並べた各ジョブの最終機械での完了時刻
+    pub fn completion_times(&self, sequence: &[usize]) -> Vec<u64> {
+        // 各機械で直前のジョブが完了した時刻
+        let mut machine_free = vec![0; self.number_of_machines()];
+        sequence
+            .iter()
+            .map(|&job| {
+                let mut ready = 0;
+                machine_free
+                    .iter_mut()
+                    .zip(&self.processing_times)
+                    .for_each(|(free, times)| {
+                        *free = (*free).max(ready) + times[job];
+                        ready = *free;
+                    });
+                ready
+            })
+            .collect()
+    }
+
+    /// メイクスパン、総納期遅れ、総フロー時間
+    pub fn schedule_objectives(&self, sequence: &[usize]) -> [u64; 3] {
+        let completion = self.completion_times(sequence);
+        let makespan = completion.last().copied().unwrap_or(0);
+        let tardiness = sequence
+            .iter()
+            .zip(&completion)
+            .map(|(&job, &c)| c.saturating_sub(self.due_dates[job]))
+            .sum();
+        let flow_time = completion.iter().sum();
+        [makespan, tardiness, flow_time]
+    }
+}
+
+impl Problem for FlowShop {
+    type Solution = JobSequence;
+
+    fn number_of_objectives(&self) -> usize {
+        3
+    }
+
+    /// 処理順がジョブの順列でなければ、すべての目的関数値を無限大とする
+    ///
+    /// 順列からの外れ具合は [`Problem::constraint_violation`] で報告する。
+    fn evaluate(&self, x: &Self::Solution) -> Objectives {
+        if !self.is_valid_sequence(x) {
+            return vec![f64::INFINITY; self.number_of_objectives()];
+        }
+        self.schedule_objectives(x)
+            .into_iter()
+            .map(|v| v as f64)
+            .collect()
+    }
+
+    /// 処理順がジョブの順列であること
+    fn number_of_constraints(&self) -> usize {
+        1
+    }
+
+    fn constraint_violation(&self, x: &Self::Solution) -> Vec<f64> {
+        vec![permutation_violation(x, self.number_of_jobs()) as f64]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_flowshop() -> FlowShop {
+        FlowShop::new(vec![vec![3, 2, 4], vec![2, 5, 1]], vec![5, 8, 14])
+    }
+
+    #[test]
+    fn test_completion_times() {
+        let flowshop = create_flowshop();
+        // 機械1: 0-3, 3-5, 5-9
+        // 機械2: 3-5, 5-10, 10-11
+        assert_eq!(flowshop.completion_times(&[0, 1, 2]), vec![5, 10, 11]);
+        // 機械1: 0-4, 4-6, 6-9
+        // 機械2: 4-5, 6-11, 11-13
+        assert_eq!(flowshop.completion_times(&[2, 1, 0]), vec![5, 11, 13]);
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let flowshop = create_flowshop();
+        // 納期遅れはジョブ1の 10 - 8 = 2 のみ
+        assert_eq!(
+            flowshop.evaluate(&vec![0, 1, 2]),
+            vec![11.0, 2.0, 26.0]
+        );
+        // ジョブ1が 11 - 8 = 3、ジョブ0が 13 - 5 = 8 遅れる
+        assert_eq!(
+            flowshop.evaluate(&vec![2, 1, 0]),
+            vec![13.0, 11.0, 29.0]
+        );
+    }
+
+    #[test]
+    fn test_evaluate_invalid_sequence() {
+        let flowshop = create_flowshop();
+        assert!(flowshop.is_valid_sequence(&[2, 0, 1]));
+        assert_eq!(
+            flowshop.constraint_violation(&vec![2, 0, 1]),
+            vec![0.0]
+        );
+        // ジョブ2がなく、ジョブ0が重複している
+        let sequence = vec![0, 0, 1];
+        assert!(!flowshop.is_valid_sequence(&sequence));
+        assert_eq!(flowshop.constraint_violation(&sequence), vec![2.0]);
+        assert_eq!(flowshop.evaluate(&sequence), vec![f64::INFINITY; 3]);
+        // 範囲外のジョブでもpanicしない
+        assert_eq!(
+            flowshop.evaluate(&vec![0, 1, 3]),
+            vec![f64::INFINITY; 3]
+        );
+    }
+}
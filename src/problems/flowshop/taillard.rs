@@ -0,0 +1,166 @@
+//! Taillard 形式のインスタンスの読み込み
+//!
+//! Taillard のベンチマークの形式に、納期のセクションを加えたものを読み込む。
+//! ```text
+//! number of jobs, number of machines, initial seed, upper bound and lower bound :
+//!           20           5   873654221        1278        1232
+//! processing times :
+//!  54 83 15 71 77 36 53 38 27 87 76 91 14 29 12 77 32 87 68 94
+//!  ...
+//! due dates :
+//!  1520 1480 ...
+//! ```
+//! 処理時間は機械ごとに1行（ジョブの順）で与える。値は複数行にまたがってもよい。
+//! 複数のインスタンスを含むファイルでは最初のインスタンスのみを読み込む。
+
+use std::path::Path;
+
+use crate::problems::parse::{LoadError, lines, read_file, syntax_error};
+
+use super::FlowShop;
+
+/// 読み込み中のセクション
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Section {
+    Header,
+    ProcessingTimes,
+    DueDates,
+}
+
+impl FlowShop {
+    /// Taillard 形式のファイルから問題を読み込む
+    pub fn from_taillard_file(path: &Path) -> Result<Self, LoadError> {
+        Self::from_taillard_str(&read_file(path)?)
+    }
+
+    /// Taillard 形式の文字列から問題を読み込む
+    pub fn from_taillard_str(content: &str) -> Result<Self, LoadError> {
+        let mut header: Vec<u64> = Vec::new();
+        let mut processing: Vec<u64> = Vec::new();
+        let mut due_dates: Vec<u64> = Vec::new();
+        let mut section = None;
+
+        for (line_no, line) in lines(content) {
+            if line.chars().any(|c| c.is_ascii_alphabetic()) {
+                let lower = line.to_ascii_lowercase();
+                section = if lower.starts_with("number of jobs") {
+                    if section.is_some() {
+                        break;
+                    }
+                    Some(Section::Header)
+                } else if lower.starts_with("processing times") {
+                    Some(Section::ProcessingTimes)
+                } else if lower.starts_with("due dates") {
+                    Some(Section::DueDates)
+                } else {
+                    return Err(syntax_error(line_no, line));
+                };
+                continue;
+            }
+
+            let values = line
+                .split_whitespace()
+                .map(|v| v.parse::<u64>())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|_| syntax_error(line_no, line))?;
+            match section {
+                Some(Section::Header) => header.extend(values),
+                Some(Section::ProcessingTimes) => {
+                    processing.extend(values)
+                }
+                Some(Section::DueDates) => due_dates.extend(values),
+                None => return Err(syntax_error(line_no, line)),
+            }
+        }
+
+        let (number_of_jobs, number_of_machines) = match header[..] {
+            [jobs, machines, ..] => (jobs as usize, machines as usize),
+            _ => {
+                return Err(LoadError::MissingField {
+                    field: "number of jobs",
+                });
+            }
+        };
+        for (field, values, expected) in [
+            (
+                "processing times",
+                &processing,
+                number_of_jobs * number_of_machines,
+            ),
+            ("due dates", &due_dates, number_of_jobs),
+        ] {
+            if values.is_empty() {
+                return Err(LoadError::MissingField { field });
+            }
+            if values.len() != expected {
+                return Err(LoadError::DimensionMismatch {
+                    field,
+                    expected,
+                    found: values.len(),
+                });
+            }
+        }
+
+        Ok(Self::new(
+            processing
+                .chunks(number_of_jobs)
+                .map(|row| row.to_vec())
+                .collect(),
+            due_dates,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTENT: &str = "number of jobs, number of machines, initial seed, upper bound and lower bound :
+           3           2   873654221          12          10
+processing times :
+  3 2 4
+  2 5
+  1
+due dates :
+  5 8 14
+number of jobs, number of machines, initial seed, upper bound and lower bound :
+           4           1   379008056          10          10
+";
+
+    #[test]
+    fn test_from_taillard_str() {
+        let flowshop = FlowShop::from_taillard_str(CONTENT).unwrap();
+        assert_eq!(flowshop.number_of_jobs(), 3);
+        assert_eq!(flowshop.number_of_machines(), 2);
+        assert_eq!(
+            flowshop.processing_times,
+            vec![vec![3, 2, 4], vec![2, 5, 1]]
+        );
+        assert_eq!(flowshop.due_dates, vec![5, 8, 14]);
+    }
+
+    #[test]
+    fn test_from_taillard_str_errors() {
+        let missing = CONTENT.replace("due dates :\n  5 8 14\n", "");
+        assert!(matches!(
+            FlowShop::from_taillard_str(&missing),
+            Err(LoadError::MissingField { field: "due dates" })
+        ));
+        let short = CONTENT.replace("  1\n", "");
+        assert!(matches!(
+            FlowShop::from_taillard_str(&short),
+            Err(LoadError::DimensionMismatch {
+                field: "processing times",
+                expected: 6,
+                found: 5
+            })
+        ));
+        let broken = CONTENT.replace("2 5", "2 x");
+        match FlowShop::from_taillard_str(&broken) {
+            Err(LoadError::Syntax { line, .. }) => {
+                assert_eq!(line, 5)
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+}
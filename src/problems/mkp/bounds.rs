@@ -0,0 +1,384 @@
+//! 目的関数値の上下界と変数固定
+//!
+//! 線形緩和（Dantzig 上界）による各目的関数の上界、貪欲解による理想点と
+//! ナディア点の推定、効率的な解で値が決まっているアイテムの固定を行う。
+
+use crate::individual::{Individual, Objectives};
+
+use super::{MKP, fill_in_order, total_profits};
+
+/// アイテムの固定状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FixedValue {
+    /// 固定されない
+    Free,
+    /// 0に固定する
+    Zero,
+    /// 1に固定する
+    One,
+}
+
+/// 各アイテムの固定状態を求める
+///
+/// - 単独でいずれかの容量を超えるアイテムは、どの実行可能解にも入らないため0
+/// - すべての利益が0のアイテムは、取り除いても目的関数値が変わらないため0
+/// - 利益が正で、0に固定されない他のアイテムをすべて入れても入るアイテムは、
+///   入れると必ず改善するため1
+pub fn fix_variables(mkp: &MKP) -> Vec<FixedValue> {
+    let zero: Vec<bool> = (0..mkp.number_of_items)
+        .map(|item| {
+            mkp.weight
+                .iter()
+                .zip(mkp.capacity.iter())
+                .any(|(w, &c)| w[item] > c)
+                || mkp.profit.iter().all(|p| p[item] == 0)
+        })
+        .collect();
+    // 0に固定されないアイテムの重さの合計
+    let free_weights: Vec<usize> = mkp
+        .weight
+        .iter()
+        .map(|w| {
+            w.iter()
+                .zip(zero.iter())
+                .filter(|&(_, &z)| !z)
+                .map(|(&w_i, _)| w_i)
+                .sum()
+        })
+        .collect();
+
+    (0..mkp.number_of_items)
+        .map(|item| {
+            if zero[item] {
+                return FixedValue::Zero;
+            }
+            // 他のアイテムがどう入っていても、このアイテムを加えられる
+            let always_fits = mkp
+                .weight
+                .iter()
+                .zip(mkp.capacity.iter())
+                .zip(free_weights.iter())
+                .all(|((w, &c), &total)| w[item] == 0 || total <= c);
+            if always_fits {
+                FixedValue::One
+            } else {
+                FixedValue::Free
+            }
+        })
+        .collect()
+}
+
+/// 単一の制約について、線形緩和の最適値（Dantzig 上界）を求める
+///
+/// 利益重量比の大きい順に詰め、入りきらないアイテムは分数で入れる。
+fn dantzig_bound(
+    profit: &[u64],
+    weight: &[usize],
+    capacity: usize,
+    items: &[usize],
+) -> f64 {
+    let ratio = |i: usize| {
+        if weight[i] == 0 {
+            f64::INFINITY
+        } else {
+            profit[i] as f64 / weight[i] as f64
+        }
+    };
+    let mut order = items.to_vec();
+    order.sort_by(|&a, &b| ratio(b).total_cmp(&ratio(a)));
+
+    let mut remaining = capacity;
+    let mut bound = 0.0;
+    for item in order {
+        if weight[item] <= remaining {
+            remaining -= weight[item];
+            bound += profit[item] as f64;
+        } else {
+            bound += profit[item] as f64 * remaining as f64
+                / weight[item] as f64;
+            break;
+        }
+    }
+    bound
+}
+
+/// 各目的関数の上界
+///
+/// 各制約について単一制約の線形緩和を解き、最小値の切り捨てを上界とする。
+/// 0に固定されるアイテムは除いて計算する。
+pub fn upper_bounds(mkp: &MKP) -> Vec<u64> {
+    let fixed = fix_variables(mkp);
+    let items: Vec<usize> = (0..mkp.number_of_items)
+        .filter(|&item| fixed[item] != FixedValue::Zero)
+        .collect();
+
+    mkp.profit
+        .iter()
+        .map(|p| {
+            mkp.weight
+                .iter()
+                .zip(mkp.capacity.iter())
+                .map(|(w, &c)| dantzig_bound(p, w, c, &items))
+                .fold(f64::INFINITY, f64::min)
+                .floor() as u64
+        })
+        .collect()
+}
+
+/// 目的関数 `objective` の利益と正規化した重さの比が大きい順に詰めた解
+fn greedy_solution(mkp: &MKP, objective: usize) -> Individual {
+    let ratio = |i: usize| {
+        let weight = mkp.normalised_weight(i);
+        if weight == 0.0 {
+            f64::INFINITY
+        } else {
+            mkp.profit[objective][i] as f64 / weight
+        }
+    };
+    let mut order: Vec<usize> = (0..mkp.number_of_items).collect();
+    order.sort_by(|&a, &b| ratio(b).total_cmp(&ratio(a)));
+    fill_in_order(mkp, &order)
+}
+
+/// 理想点とナディア点の推定
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ObjectiveBounds {
+    /// 理想点の上界（線形緩和による上界）
+    pub ideal_upper: Vec<u64>,
+    /// 理想点の下界（各目的関数の貪欲解で達成できる値）
+    pub ideal_lower: Vec<u64>,
+    /// ナディア点の推定（ペイオフ表の各列の最小値）
+    pub nadir: Vec<u64>,
+}
+
+impl ObjectiveBounds {
+    /// 理想点とナディア点を推定する
+    ///
+    /// 各目的関数について貪欲解を求めてペイオフ表を作り、対角成分を理想点の
+    /// 下界、各列の最小値をナディア点の推定とする。
+    pub fn estimate(mkp: &MKP) -> Self {
+        let payoff: Vec<Vec<u64>> = (0..mkp.number_of_obj)
+            .map(|k| total_profits(mkp, &greedy_solution(mkp, k)))
+            .collect();
+        let ideal_lower: Vec<u64> =
+            (0..mkp.number_of_obj).map(|k| payoff[k][k]).collect();
+        let nadir = (0..mkp.number_of_obj)
+            .map(|k| payoff.iter().map(|row| row[k]).min().unwrap_or(0))
+            .collect();
+
+        Self {
+            ideal_upper: upper_bounds(mkp),
+            ideal_lower,
+            nadir,
+        }
+    }
+
+    /// 目的関数値をナディア点が0、理想点の上界が1となるように正規化する
+    ///
+    /// ナディア点と理想点の上界が等しい目的関数は0とする。
+    pub fn normalise(&self, f: &Objectives) -> Objectives {
+        f.iter()
+            .zip(self.nadir.iter().zip(self.ideal_upper.iter()))
+            .map(|(&v, (&lo, &hi))| {
+                if hi > lo {
+                    (v - lo as f64) / (hi - lo) as f64
+                } else {
+                    0.0
+                }
+            })
+            .collect()
+    }
+}
+
+/// 固定されたアイテムを取り除いたインスタンス
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReducedMkp {
+    /// 固定されないアイテムだけからなるインスタンス
+    ///
+    /// 容量は1に固定したアイテムの重さを差し引いたもの。
+    pub mkp: MKP,
+    /// 縮小後のアイテムに対応する元のアイテム番号
+    pub free_items: Vec<usize>,
+    /// 元のアイテムの固定状態
+    pub fixed: Vec<FixedValue>,
+    /// 1に固定したアイテムの利益の合計
+    pub offset: Vec<u64>,
+}
+
+impl ReducedMkp {
+    /// 変数固定でインスタンスを縮小する
+    pub fn new(mkp: &MKP) -> Self {
+        let fixed = fix_variables(mkp);
+        let free_items: Vec<usize> = (0..mkp.number_of_items)
+            .filter(|&item| fixed[item] == FixedValue::Free)
+            .collect();
+        let ones: Individual = fixed
+            .iter()
+            .map(|&v| (v == FixedValue::One) as u8)
+            .collect();
+        let select = |row: &Vec<usize>| -> Vec<usize> {
+            free_items.iter().map(|&i| row[i]).collect()
+        };
+
+        let capacity = mkp
+            .weight
+            .iter()
+            .zip(mkp.capacity.iter())
+            .map(|(w, &c)| {
+                let used: usize = w
+                    .iter()
+                    .zip(ones.iter())
+                    .filter(|&(_, &b)| b == 1)
+                    .map(|(&w_i, _)| w_i)
+                    .sum();
+                c - used
+            })
+            .collect();
+        let reduced = MKP {
+            number_of_obj: mkp.number_of_obj,
+            number_of_items: free_items.len(),
+            capacity,
+            profit: mkp
+                .profit
+                .iter()
+                .map(|p| free_items.iter().map(|&i| p[i]).collect())
+                .collect(),
+            weight: mkp.weight.iter().map(select).collect(),
+        };
+
+        Self {
+            mkp: reduced,
+            offset: total_profits(mkp, &ones),
+            free_items,
+            fixed,
+        }
+    }
+
+    /// 縮小後の解を元のインスタンスの解に戻す
+    pub fn expand(&self, x: &Individual) -> Individual {
+        let mut expanded: Individual = self
+            .fixed
+            .iter()
+            .map(|&v| (v == FixedValue::One) as u8)
+            .collect();
+        self.free_items
+            .iter()
+            .zip(x.iter())
+            .for_each(|(&item, &bit)| expanded[item] = bit);
+        expanded
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{MKPInstance, enumerate_pareto_front, fit_mkp};
+    use super::*;
+
+    fn create_mkp() -> MKP {
+        MKP {
+            number_of_obj: 2,
+            number_of_items: 5,
+            capacity: vec![10],
+            profit: vec![vec![10, 2, 6, 8, 1], vec![4, 3, 12, 8, 9]],
+            weight: vec![vec![5, 4, 4, 5, 3]],
+        }
+    }
+
+    #[test]
+    fn test_dantzig_bound() {
+        // 比: [2.0, 0.5, 1.5, 1.6, 0.33]
+        // アイテム0 (5) + アイテム3 (5) で容量10をちょうど使い切る
+        let mkp = create_mkp();
+        let items: Vec<usize> = (0..5).collect();
+        let bound = dantzig_bound(
+            &mkp.profit[0],
+            &mkp.weight[0],
+            mkp.capacity[0],
+            &items,
+        );
+        assert_eq!(bound, 18.0);
+        // 目的関数1の比: [0.8, 0.75, 3.0, 1.6, 3.0]
+        // アイテム2 (4) + アイテム4 (3) + アイテム3 の 3/5
+        let bound = dantzig_bound(
+            &mkp.profit[1],
+            &mkp.weight[0],
+            mkp.capacity[0],
+            &items,
+        );
+        assert!((bound - (12.0 + 9.0 + 8.0 * 0.6)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_bounds_enclose_exact_front() {
+        // 厳密なフロントの各目的関数の最大値は理想点の上下界の間にある
+        let mkp = MKP::from_instance(MKPInstance::P2N20Ins1).unwrap();
+        let front = enumerate_pareto_front(&mkp);
+        let bounds = ObjectiveBounds::estimate(&mkp);
+        (0..mkp.number_of_obj).for_each(|k| {
+            let best = front.iter().map(|p| p.profits[k]).max().unwrap();
+            assert!(bounds.ideal_lower[k] <= best);
+            assert!(best <= bounds.ideal_upper[k]);
+            assert!(bounds.nadir[k] <= bounds.ideal_lower[k]);
+        });
+    }
+
+    #[test]
+    fn test_normalise() {
+        let bounds = ObjectiveBounds {
+            ideal_upper: vec![10, 5],
+            ideal_lower: vec![8, 5],
+            nadir: vec![0, 5],
+        };
+        assert_eq!(bounds.normalise(&vec![5.0, 5.0]), vec![0.5, 0.0]);
+    }
+
+    #[test]
+    fn test_fix_variables() {
+        // アイテム0は容量超過、アイテム1は利益0、アイテム3は重さ0
+        let mkp = MKP {
+            number_of_obj: 2,
+            number_of_items: 4,
+            capacity: vec![10],
+            profit: vec![vec![5, 0, 3, 1], vec![1, 0, 2, 1]],
+            weight: vec![vec![11, 2, 6, 0]],
+        };
+        assert_eq!(
+            fix_variables(&mkp),
+            vec![
+                FixedValue::Zero,
+                FixedValue::Zero,
+                FixedValue::One,
+                FixedValue::One
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reduced_front_matches_original() {
+        // 縮小したインスタンスのフロントに固定分を加えると元のフロントになる
+        let mkp = MKP {
+            number_of_obj: 2,
+            number_of_items: 7,
+            capacity: vec![12],
+            profit: vec![
+                vec![10, 2, 6, 8, 1, 4, 0],
+                vec![4, 3, 12, 8, 9, 2, 0],
+            ],
+            weight: vec![vec![5, 4, 4, 5, 3, 0, 1]],
+        };
+        let reduced = ReducedMkp::new(&mkp);
+        assert_eq!(reduced.free_items, vec![0, 1, 2, 3, 4]);
+        assert_eq!(reduced.offset, vec![4, 2]);
+
+        let expanded: Vec<Objectives> =
+            enumerate_pareto_front(&reduced.mkp)
+                .iter()
+                .map(|p| fit_mkp(&mkp, &reduced.expand(&p.x)))
+                .collect();
+        let original: Vec<Objectives> = enumerate_pareto_front(&mkp)
+            .iter()
+            .map(|p| p.objectives())
+            .collect();
+        assert_eq!(expanded, original);
+    }
+}
@@ -0,0 +1,214 @@
+//! 差分評価
+//!
+//! 利益と重さの合計を保持しておき、1ビットの反転による変化だけを反映する。
+//! 反転1回あたりの計算量は目的関数と制約の数に比例し、アイテム数によらない。
+
+use crate::individual::{Individual, Objectives};
+
+use super::{MKP, penalised_objectives, total_profits, total_weights};
+
+/// 利益と重さの合計を保持した評価状態
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MkpEvalState {
+    x: Individual,
+    /// 各目的関数の利益の合計
+    profits: Vec<u64>,
+    /// 各制約の重さの合計
+    weights: Vec<usize>,
+}
+
+impl MkpEvalState {
+    /// 解を評価して評価状態を作る
+    pub fn new(mkp: &MKP, x: Individual) -> Self {
+        let profits = total_profits(mkp, &x);
+        let weights = total_weights(mkp, &x);
+        Self {
+            x,
+            profits,
+            weights,
+        }
+    }
+
+    /// 現在の解
+    pub fn x(&self) -> &Individual {
+        &self.x
+    }
+
+    /// 現在の解を取り出す
+    pub fn into_individual(self) -> Individual {
+        self.x
+    }
+
+    /// 各目的関数の利益の合計
+    pub fn profits(&self) -> &[u64] {
+        &self.profits
+    }
+
+    /// 各制約の重さの合計
+    pub fn weights(&self) -> &[usize] {
+        &self.weights
+    }
+
+    /// すべての容量制約を満たす場合true
+    pub fn is_feasible(&self, mkp: &MKP) -> bool {
+        mkp.is_within_capacity(&self.weights)
+    }
+
+    /// `fit_mkp` と同じ目的関数値
+    pub fn objectives(&self, mkp: &MKP) -> Objectives {
+        penalised_objectives(mkp, &self.profits, &self.weights)
+    }
+
+    /// `item` 番目のビットを反転する
+    pub fn flip(&mut self, mkp: &MKP, item: usize) {
+        let added = self.x[item] == 0;
+        self.x[item] = 1 - self.x[item];
+
+        self.profits.iter_mut().zip(mkp.profit.iter()).for_each(
+            |(f, p)| {
+                if added {
+                    *f += p[item];
+                } else {
+                    *f -= p[item];
+                }
+            },
+        );
+        self.weights.iter_mut().zip(mkp.weight.iter()).for_each(
+            |(total, w)| {
+                if added {
+                    *total += w[item];
+                } else {
+                    *total -= w[item];
+                }
+            },
+        );
+    }
+
+    /// `items` 番目のビットをすべて反転した場合の各目的関数の利益の合計
+    ///
+    /// `items` に同じアイテムが複数含まれていてはならない。
+    /// 評価状態は変更しない。
+    pub fn profits_after_flips(
+        &self,
+        mkp: &MKP,
+        items: &[usize],
+    ) -> Vec<u64> {
+        self.profits
+            .iter()
+            .zip(mkp.profit.iter())
+            .map(|(&f, p)| {
+                items.iter().fold(f, |f, &item| {
+                    if self.x[item] == 0 {
+                        f + p[item]
+                    } else {
+                        f - p[item]
+                    }
+                })
+            })
+            .collect()
+    }
+
+    /// `items` 番目のビットをすべて反転した場合の各制約の重さの合計
+    ///
+    /// `items` に同じアイテムが複数含まれていてはならない。
+    /// 評価状態は変更しない。
+    pub fn weights_after_flips(
+        &self,
+        mkp: &MKP,
+        items: &[usize],
+    ) -> Vec<usize> {
+        self.weights
+            .iter()
+            .zip(mkp.weight.iter())
+            .map(|(&total, w)| {
+                items.iter().fold(total, |total, &item| {
+                    if self.x[item] == 0 {
+                        total + w[item]
+                    } else {
+                        total - w[item]
+                    }
+                })
+            })
+            .collect()
+    }
+
+    /// `item` 番目のビットを反転した場合の目的関数値
+    ///
+    /// 評価状態は変更しない。
+    pub fn objectives_after_flip(
+        &self,
+        mkp: &MKP,
+        item: usize,
+    ) -> Objectives {
+        penalised_objectives(
+            mkp,
+            &self.profits_after_flips(mkp, &[item]),
+            &self.weights_after_flips(mkp, &[item]),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{MKPInstance, fit_mkp};
+    use super::*;
+
+    #[test]
+    fn test_flip_matches_full_evaluation() {
+        // 反転を繰り返しても全体を評価し直した結果と一致する
+        let mkp = MKP::from_instance(MKPInstance::P2N100Ins1).unwrap();
+        let mut state = MkpEvalState::new(&mkp, vec![0; 100]);
+        (0..300).for_each(|step| {
+            let item = (step * 37) % 100;
+            let expected = {
+                let mut x = state.x().clone();
+                x[item] = 1 - x[item];
+                fit_mkp(&mkp, &x)
+            };
+            assert_eq!(state.objectives_after_flip(&mkp, item), expected);
+
+            state.flip(&mkp, item);
+            assert_eq!(state.objectives(&mkp), expected);
+            assert_eq!(state.weights(), total_weights(&mkp, state.x()));
+        });
+    }
+
+    #[test]
+    fn test_after_flips_matches_full_evaluation() {
+        // アイテム0を取り除き、アイテム2を加える
+        let mkp = MKP::from_instance(MKPInstance::P2N100Ins1).unwrap();
+        let mut x = vec![0; 100];
+        x[0] = 1;
+        x[4] = 1;
+        let state = MkpEvalState::new(&mkp, x.clone());
+        x[0] = 0;
+        x[2] = 1;
+        assert_eq!(
+            state.profits_after_flips(&mkp, &[0, 2]),
+            total_profits(&mkp, &x)
+        );
+        assert_eq!(
+            state.weights_after_flips(&mkp, &[0, 2]),
+            total_weights(&mkp, &x)
+        );
+    }
+
+    #[test]
+    fn test_infeasible_penalty() {
+        // 重さ 5 + 4 = 9 > 容量8 のため、超過量1のペナルティ
+        let mkp = MKP {
+            number_of_obj: 2,
+            number_of_items: 3,
+            capacity: vec![8],
+            profit: vec![vec![1, 2, 3], vec![4, 5, 6]],
+            weight: vec![vec![5, 4, 3]],
+        };
+        let mut state = MkpEvalState::new(&mkp, vec![1, 0, 0]);
+        assert!(state.is_feasible(&mkp));
+        state.flip(&mkp, 1);
+        assert!(!state.is_feasible(&mkp));
+        assert_eq!(state.objectives(&mkp), vec![-1.0, -1.0]);
+        assert_eq!(state.profits(), &[3, 9]);
+        assert_eq!(state.into_individual(), vec![1, 1, 0]);
+    }
+}
@@ -0,0 +1,106 @@
+//! インスタンスの読み込みと書き出しのエラー
+
+use std::{fmt, io, path::PathBuf};
+
+/// インスタンスの読み込みと書き出しで発生するエラー
+///
+/// どのフィールドが不正かを呼び出し側で判別できるよう、フィールド名と
+/// JSONパス（例: `$.profit[1][3]`）を保持する。
+#[derive(Debug)]
+pub enum MkpLoadError {
+    /// ファイルを読み込めない
+    Io { path: PathBuf, source: io::Error },
+
+    /// ファイルに書き出せない
+    Write { path: PathBuf, source: io::Error },
+
+    /// JSONとして解釈できない
+    Json(serde_json::Error),
+
+    /// 必須のフィールドがない
+    MissingField { field: &'static str, path: String },
+
+    /// フィールドの型が不正
+    InvalidType {
+        field: &'static str,
+        path: String,
+        expected: &'static str,
+    },
+
+    /// 配列の長さなどの次元が一致しない
+    DimensionMismatch {
+        field: &'static str,
+        path: String,
+        expected: usize,
+        found: usize,
+    },
+
+    /// テキスト形式の行を解釈できない
+    Syntax { line: usize, content: String },
+
+    /// インスタンスを指定の形式で書き出せない
+    UnsupportedFormat {
+        format: &'static str,
+        reason: String,
+    },
+}
+
+impl fmt::Display for MkpLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io { path, source } => {
+                write!(f, "failed to read {}: {}", path.display(), source)
+            }
+            Self::Write { path, source } => {
+                write!(f, "failed to write {}: {}", path.display(), source)
+            }
+            Self::Json(e) => write!(f, "invalid JSON: {}", e),
+            Self::MissingField { field, path } => {
+                write!(f, "missing field `{}` at {}", field, path)
+            }
+            Self::InvalidType {
+                field,
+                path,
+                expected,
+            } => write!(
+                f,
+                "invalid type for field `{}` at {}: expected {}",
+                field, path, expected
+            ),
+            Self::DimensionMismatch {
+                field,
+                path,
+                expected,
+                found,
+            } => write!(
+                f,
+                "dimension mismatch for field `{}` at {}: expected {}, found {}",
+                field, path, expected, found
+            ),
+            Self::Syntax { line, content } => {
+                write!(f, "syntax error at line {}: {}", line, content)
+            }
+            Self::UnsupportedFormat { format, reason } => {
+                write!(f, "cannot write as {}: {}", format, reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for MkpLoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io { source, .. } | Self::Write { source, .. } => {
+                Some(source)
+            }
+            Self::Json(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for MkpLoadError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::Json(e)
+    }
+}
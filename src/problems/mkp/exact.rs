@@ -0,0 +1,330 @@
+//! 厳密なパレートフロントの計算
+//!
+//! アイテム数が少ない場合は、枝刈り付きの全列挙でパレートフロントを求める。
+//! アイテム数が多い2目的の場合は、Nemhauser–Ullmann 型の動的計画法で求める。
+//! 得られたフロントはIGDなどの指標の参照フロントとして使う。
+
+use std::collections::BTreeMap;
+
+use crate::individual::{Individual, Objectives};
+
+use super::{MKP, MkpEvalState, weakly_dominates};
+
+/// 全列挙で扱うアイテム数の上限
+pub const MAX_ENUMERATION_ITEMS: usize = 25;
+
+/// パレートフロント上の解
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParetoPoint {
+    /// 解
+    pub x: Individual,
+    /// 各目的関数の利益
+    pub profits: Vec<u64>,
+}
+
+impl ParetoPoint {
+    /// `fit_mkp` と同じ形式の目的関数値
+    pub fn objectives(&self) -> Objectives {
+        self.profits.iter().map(|&p| p as f64).collect()
+    }
+}
+
+/// 探索中の部分解
+type Label = MkpEvalState;
+
+/// アイテムを1つも入れていない部分解
+fn empty_label(mkp: &MKP) -> Label {
+    MkpEvalState::new(mkp, vec![0; mkp.number_of_items])
+}
+
+/// `item` 番目のアイテムを加えた部分解を返す
+///
+/// 容量制約を満たさない場合は `None` を返す。
+fn with_item(mkp: &MKP, label: &Label, item: usize) -> Option<Label> {
+    if !mkp.is_within_capacity(&label.weights_after_flips(mkp, &[item])) {
+        return None;
+    }
+    let mut included = label.clone();
+    included.flip(mkp, item);
+    Some(included)
+}
+
+/// 利益が大きくかつ重さが小さいという意味で `a` が `b` を弱支配する場合true
+fn label_dominates(a: &Label, b: &Label) -> bool {
+    weakly_dominates(a.profits(), b.profits())
+        && a.weights().iter().zip(b.weights()).all(|(x, y)| x <= y)
+}
+
+/// 利益で並べ替えたパレートフロントに変換する
+fn into_front(labels: Vec<Label>) -> Vec<ParetoPoint> {
+    let mut front: Vec<ParetoPoint> = labels
+        .into_iter()
+        .map(|label| ParetoPoint {
+            profits: label.profits().to_vec(),
+            x: label.into_individual(),
+        })
+        .collect();
+    front.sort_by(|a, b| a.profits.cmp(&b.profits));
+    front
+}
+
+/// 各目的関数について、`i` 番目以降のアイテムの利益の合計
+fn suffix_profits(mkp: &MKP) -> Vec<Vec<u64>> {
+    mkp.profit
+        .iter()
+        .map(|p| {
+            let mut sums = vec![0; p.len() + 1];
+            (0..p.len())
+                .rev()
+                .for_each(|i| sums[i] = sums[i + 1] + p[i]);
+            sums
+        })
+        .collect()
+}
+
+/// 深さ優先で部分解を展開する
+///
+/// 残りのアイテムをすべて加えても利益がアーカイブ中の解に弱支配される場合は
+/// 枝刈りする。重さは単調に増えるため、容量を超えた時点でも枝刈りする。
+fn enumerate(
+    mkp: &MKP,
+    suffix: &[Vec<u64>],
+    item: usize,
+    label: Label,
+    archive: &mut Vec<Label>,
+) {
+    let bound: Vec<u64> = label
+        .profits()
+        .iter()
+        .zip(suffix.iter())
+        .map(|(&f, s)| f + s[item])
+        .collect();
+    if archive
+        .iter()
+        .any(|a| weakly_dominates(a.profits(), &bound))
+    {
+        return;
+    }
+
+    if item == mkp.number_of_items {
+        archive
+            .retain(|a| !weakly_dominates(label.profits(), a.profits()));
+        archive.push(label);
+        return;
+    }
+
+    // アイテムを加える側を先に調べ、良い解を早くアーカイブに入れる
+    if let Some(included) = with_item(mkp, &label, item) {
+        enumerate(mkp, suffix, item + 1, included, archive);
+    }
+    enumerate(mkp, suffix, item + 1, label, archive);
+}
+
+/// 枝刈り付きの全列挙でパレートフロントを求める
+///
+/// 目的関数値が同じ解は1つだけ返す。
+/// 計算量はアイテム数について指数的に増えるため、
+/// アイテム数が [`MAX_ENUMERATION_ITEMS`] 以下の場合を想定する。
+pub fn enumerate_pareto_front(mkp: &MKP) -> Vec<ParetoPoint> {
+    let suffix = suffix_profits(mkp);
+    let mut archive = Vec::new();
+    enumerate(mkp, &suffix, 0, empty_label(mkp), &mut archive);
+    into_front(archive)
+}
+
+/// 他の部分解に支配される部分解を取り除く
+///
+/// 重さの合計の昇順に並べると、支配する側は必ず前にある。
+/// 2目的・単一容量の場合は、利益の階段状の境界を使って判定する。
+fn remove_dominated_labels(
+    mkp: &MKP,
+    mut labels: Vec<Label>,
+) -> Vec<Label> {
+    labels.sort_by_key(|label| {
+        let weight: usize = label.weights().iter().sum();
+        let profit: u64 = label.profits().iter().sum();
+        (weight, std::cmp::Reverse(profit))
+    });
+
+    if mkp.number_of_obj != 2 || mkp.number_of_constraints() != 1 {
+        let mut kept: Vec<Label> = Vec::new();
+        labels.into_iter().for_each(|label| {
+            if !kept.iter().any(|k| label_dominates(k, &label)) {
+                kept.push(label);
+            }
+        });
+        return kept;
+    }
+
+    // 第1目的の利益 → 第2目的の利益（第1目的の昇順に第2目的は減少）
+    let mut staircase: BTreeMap<u64, u64> = BTreeMap::new();
+    labels
+        .into_iter()
+        .filter(|label| {
+            let (p0, p1) = (label.profits()[0], label.profits()[1]);
+            if staircase.range(p0..).next().is_some_and(|(_, &v)| v >= p1)
+            {
+                return false;
+            }
+
+            let removed: Vec<u64> = staircase
+                .range(..=p0)
+                .rev()
+                .take_while(|(_, v)| **v <= p1)
+                .map(|(&k, _)| k)
+                .collect();
+            removed.iter().for_each(|k| {
+                staircase.remove(k);
+            });
+            staircase.insert(p0, p1);
+            true
+        })
+        .collect()
+}
+
+/// 動的計画法でパレートフロントを求める
+///
+/// アイテムを1つずつ加えながら、利益と重さの両方で支配されない部分解だけを
+/// 残す（Nemhauser–Ullmann）。最後に利益だけで支配される解を取り除く。
+/// 部分解の数は利益の組み合わせの数で抑えられるため、2目的であれば
+/// 全列挙できないアイテム数でも現実的な時間で計算できる。
+pub fn dp_pareto_front(mkp: &MKP) -> Vec<ParetoPoint> {
+    let labels = (0..mkp.number_of_items).fold(
+        vec![empty_label(mkp)],
+        |labels, item| {
+            let extended: Vec<Label> = labels
+                .iter()
+                .filter_map(|label| with_item(mkp, label, item))
+                .collect();
+            remove_dominated_labels(
+                mkp,
+                labels.into_iter().chain(extended).collect(),
+            )
+        },
+    );
+
+    // 重さを無視して利益だけで比較する
+    let mut front: Vec<Label> = Vec::new();
+    labels.into_iter().for_each(|label| {
+        if !front
+            .iter()
+            .any(|f| weakly_dominates(f.profits(), label.profits()))
+        {
+            front.retain(|f| {
+                !weakly_dominates(label.profits(), f.profits())
+            });
+            front.push(label);
+        }
+    });
+    into_front(front)
+}
+
+/// インスタンスの大きさに応じた方法でパレートフロントを求める
+///
+/// アイテム数が [`MAX_ENUMERATION_ITEMS`] 以下の場合は全列挙、
+/// 2目的の場合は動的計画法を使う。どちらにも当てはまらない場合は `None` を返す。
+pub fn exact_pareto_front(mkp: &MKP) -> Option<Vec<ParetoPoint>> {
+    if mkp.number_of_items <= MAX_ENUMERATION_ITEMS {
+        Some(enumerate_pareto_front(mkp))
+    } else if mkp.number_of_obj == 2 {
+        Some(dp_pareto_front(mkp))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{MKPInstance, fit_mkp};
+    use super::*;
+
+    fn create_mkp() -> MKP {
+        MKP {
+            number_of_obj: 2,
+            number_of_items: 5,
+            capacity: vec![10],
+            profit: vec![vec![10, 2, 6, 8, 1], vec![4, 3, 12, 8, 9]],
+            weight: vec![vec![5, 4, 4, 5, 3]],
+        }
+    }
+
+    /// 全探索で非支配な利益ベクトルを求める
+    fn brute_force_front(mkp: &MKP) -> Vec<Vec<u64>> {
+        let points: Vec<Objectives> = (0..1usize << mkp.number_of_items)
+            .map(|bits| {
+                (0..mkp.number_of_items)
+                    .map(|i| ((bits >> i) & 1) as u8)
+                    .collect::<Individual>()
+            })
+            .map(|x| fit_mkp(mkp, &x))
+            .filter(|f| f.iter().all(|&v| v >= 0.0))
+            .collect();
+
+        let mut front: Vec<Vec<u64>> = points
+            .iter()
+            .filter(|f| {
+                !points.iter().any(|g| {
+                    g.iter().zip(f.iter()).all(|(a, b)| a >= b) && g != *f
+                })
+            })
+            .map(|f| f.iter().map(|&v| v as u64).collect())
+            .collect();
+        front.sort();
+        front.dedup();
+        front
+    }
+
+    fn profits(front: &[ParetoPoint]) -> Vec<Vec<u64>> {
+        front.iter().map(|p| p.profits.clone()).collect()
+    }
+
+    #[test]
+    fn test_enumerate_matches_brute_force() {
+        let mkp = create_mkp();
+        let front = enumerate_pareto_front(&mkp);
+        assert_eq!(profits(&front), brute_force_front(&mkp));
+        // 解の目的関数値が利益と一致する
+        front.iter().for_each(|p| {
+            assert_eq!(fit_mkp(&mkp, &p.x), p.objectives());
+        });
+    }
+
+    #[test]
+    fn test_dp_matches_brute_force_multiple_constraints() {
+        // 3目的・2制約では階段状の境界を使わない経路を通る
+        let mkp = MKP {
+            number_of_obj: 3,
+            number_of_items: 6,
+            capacity: vec![10, 9],
+            profit: vec![
+                vec![10, 2, 6, 8, 1, 5],
+                vec![4, 3, 12, 8, 9, 2],
+                vec![1, 7, 3, 2, 6, 9],
+            ],
+            weight: vec![vec![5, 4, 4, 5, 3, 2], vec![2, 6, 3, 1, 4, 5]],
+        };
+        assert_eq!(
+            profits(&dp_pareto_front(&mkp)),
+            brute_force_front(&mkp)
+        );
+    }
+
+    #[test]
+    fn test_enumerate_and_dp_agree_on_bundled_instance() {
+        let mkp = MKP::from_instance(MKPInstance::P2N20Ins1).unwrap();
+        let enumerated = enumerate_pareto_front(&mkp);
+        let dp = dp_pareto_front(&mkp);
+        assert!(!enumerated.is_empty());
+        assert_eq!(profits(&enumerated), profits(&dp));
+        dp.iter().for_each(|p| {
+            assert_eq!(fit_mkp(&mkp, &p.x), p.objectives());
+        });
+    }
+
+    #[test]
+    fn test_exact_pareto_front_dispatch() {
+        let p3 = MKP::from_instance(MKPInstance::P3N100Ins1).unwrap();
+        assert!(exact_pareto_front(&p3).is_none());
+        assert!(exact_pareto_front(&create_mkp()).is_some());
+    }
+}
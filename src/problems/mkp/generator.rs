@@ -0,0 +1,247 @@
+//! ランダムなインスタンスの生成
+//!
+//! 重さは `[1, R]` の一様乱数（`R = 1000`）とし、利益は相関の種類に応じて
+//! 重さから決める。乱数には ChaCha8 を用いるので、同じ設定とシードからは
+//! `rand` のバージョンによらず同じインスタンスが生成される。
+
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::path::{Path, PathBuf};
+
+use super::{MKP, MkpLoadError};
+
+/// 重さと利益の上限
+const RANGE: u64 = 1000;
+
+/// 利益と重さの相関の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Correlation {
+    /// 無相関: `p ∈ [1, R]`
+    Uncorrelated,
+    /// 弱い相関: `p ∈ [w - R/10, w + R/10]`
+    WeaklyCorrelated,
+    /// 強い相関: `p_k = w + t_k`、`t_k ∈ [1, R/5]` は目的関数ごとに独立
+    StronglyCorrelated,
+    /// 逆相関: `p ∈ [R + 1 - w - R/10, R + 1 - w + R/10]`
+    InverselyCorrelated,
+    /// 目的関数間で競合: 各アイテムの利益ベクトルの合計が `R` になる
+    ///
+    /// 丸めのため、合計は `R` から最大で `m/2` ずれる。
+    Conflicting,
+}
+
+/// インスタンス生成の設定
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeneratorConfig {
+    /// 目的関数の数
+    pub number_of_obj: usize,
+    /// アイテムの数
+    pub number_of_items: usize,
+    /// 重さの合計に対する容量の比
+    pub capacity_ratio: f64,
+    /// 利益と重さの相関
+    pub correlation: Correlation,
+    /// 乱数のシード
+    pub seed: u64,
+}
+
+/// `problems/mkp` の命名規則に従ったファイル名
+///
+/// `KP_p-{目的関数の数}_n-{アイテムの数}_ins-{インスタンス番号}.json`
+pub fn instance_file_name(
+    number_of_obj: usize,
+    number_of_items: usize,
+    instance: usize,
+) -> String {
+    format!(
+        "KP_p-{}_n-{}_ins-{}.json",
+        number_of_obj, number_of_items, instance
+    )
+}
+
+/// 重さを中心とした幅 `R/10` の範囲で乱数を生成する（1以上）
+fn noisy(rng: &mut ChaCha8Rng, center: i64) -> u64 {
+    let spread = (RANGE / 10) as i64;
+    (center + rng.random_range(-spread..=spread)).max(1) as u64
+}
+
+/// 1つの目的関数について、各アイテムの利益を生成する
+fn correlated_profits(
+    rng: &mut ChaCha8Rng,
+    weight: &[usize],
+    correlation: Correlation,
+) -> Vec<u64> {
+    weight
+        .iter()
+        .map(|&w| match correlation {
+            Correlation::Uncorrelated | Correlation::Conflicting => {
+                rng.random_range(1..=RANGE)
+            }
+            Correlation::WeaklyCorrelated => noisy(rng, w as i64),
+            Correlation::StronglyCorrelated => {
+                w as u64 + rng.random_range(1..=RANGE / 5)
+            }
+            Correlation::InverselyCorrelated => {
+                noisy(rng, (RANGE + 1) as i64 - w as i64)
+            }
+        })
+        .collect()
+}
+
+/// 目的関数間で競合する利益を生成する
+///
+/// アイテムごとに単体上の一様乱数 `λ` を生成し、`p_k = 1 + λ_k (R - m)` とする。
+fn conflicting_profits(
+    rng: &mut ChaCha8Rng,
+    number_of_obj: usize,
+    number_of_items: usize,
+) -> Vec<Vec<u64>> {
+    let lambdas: Vec<Vec<f64>> = (0..number_of_items)
+        .map(|_| {
+            // 指数分布の乱数を正規化すると単体上の一様分布になる
+            let e: Vec<f64> = (0..number_of_obj)
+                .map(|_| -rng.random_range(f64::MIN_POSITIVE..1.0).ln())
+                .collect();
+            let sum: f64 = e.iter().sum();
+            e.iter().map(|v| v / sum).collect()
+        })
+        .collect();
+
+    (0..number_of_obj)
+        .map(|k| {
+            lambdas
+                .iter()
+                .map(|l| {
+                    1 + (l[k] * (RANGE as usize - number_of_obj) as f64)
+                        .round() as u64
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// 設定に従ってインスタンスを生成する
+pub fn generate_mkp(config: &GeneratorConfig) -> MKP {
+    let mut rng = ChaCha8Rng::seed_from_u64(config.seed);
+
+    let weight: Vec<usize> = (0..config.number_of_items)
+        .map(|_| rng.random_range(1..=RANGE) as usize)
+        .collect();
+    let profit = match config.correlation {
+        Correlation::Conflicting => conflicting_profits(
+            &mut rng,
+            config.number_of_obj,
+            config.number_of_items,
+        ),
+        correlation => (0..config.number_of_obj)
+            .map(|_| correlated_profits(&mut rng, &weight, correlation))
+            .collect(),
+    };
+
+    let total_weight: usize = weight.iter().sum();
+    let capacity = (total_weight as f64 * config.capacity_ratio).round();
+
+    MKP {
+        number_of_obj: config.number_of_obj,
+        number_of_items: config.number_of_items,
+        capacity: vec![capacity as usize],
+        profit,
+        weight: vec![weight],
+    }
+}
+
+/// インスタンスを生成し、命名規則に従ったファイル名で `dir` に書き出す
+///
+/// 書き出したファイルのパスを返す。
+pub fn write_generated_mkp(
+    dir: &Path,
+    config: &GeneratorConfig,
+    instance: usize,
+) -> Result<PathBuf, MkpLoadError> {
+    let path = dir.join(instance_file_name(
+        config.number_of_obj,
+        config.number_of_items,
+        instance,
+    ));
+    generate_mkp(config).write_to_file(&path)?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_config(correlation: Correlation) -> GeneratorConfig {
+        GeneratorConfig {
+            number_of_obj: 3,
+            number_of_items: 50,
+            capacity_ratio: 0.5,
+            correlation,
+            seed: 42,
+        }
+    }
+
+    #[test]
+    fn test_instance_file_name() {
+        assert_eq!(
+            instance_file_name(2, 100, 1),
+            "KP_p-2_n-100_ins-1.json"
+        );
+    }
+
+    #[test]
+    fn test_generate_mkp_reproducible() {
+        // 同じシードからは同じインスタンス、異なるシードからは異なるインスタンス
+        let config = create_config(Correlation::Uncorrelated);
+        assert_eq!(generate_mkp(&config), generate_mkp(&config));
+        let other = GeneratorConfig { seed: 43, ..config };
+        assert_ne!(generate_mkp(&config), generate_mkp(&other));
+    }
+
+    #[test]
+    fn test_write_generated_mkp() {
+        let config = create_config(Correlation::WeaklyCorrelated);
+        let dir = std::env::temp_dir()
+            .join(format!("nsgaii-rust-{}-generator", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = write_generated_mkp(&dir, &config, 7).unwrap();
+        assert_eq!(path, dir.join("KP_p-3_n-50_ins-7.json"));
+        assert_eq!(MKP::from_file(&path).unwrap(), generate_mkp(&config));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_generate_mkp_dimensions() {
+        let mkp = generate_mkp(&create_config(Correlation::Conflicting));
+        assert_eq!(mkp.validate().unwrap(), vec![]);
+        let total_weight: usize = mkp.weight[0].iter().sum();
+        assert_eq!(
+            mkp.capacity[0],
+            (total_weight as f64 * 0.5).round() as usize
+        );
+    }
+
+    #[test]
+    fn test_generate_mkp_strongly_correlated() {
+        let mkp =
+            generate_mkp(&create_config(Correlation::StronglyCorrelated));
+        mkp.profit.iter().for_each(|row| {
+            row.iter().zip(mkp.weight[0].iter()).for_each(|(&p, &w)| {
+                assert!((1..=RANGE / 5).contains(&(p - w as u64)))
+            });
+        });
+        // 目的関数ごとに利益が異なり、フロントが1点に縮退しない
+        assert_ne!(mkp.profit[0], mkp.profit[1]);
+        assert_ne!(mkp.profit[1], mkp.profit[2]);
+    }
+
+    #[test]
+    fn test_generate_mkp_conflicting_sum() {
+        // 各アイテムの利益の合計は丸めの誤差を除いて R になる
+        let mkp = generate_mkp(&create_config(Correlation::Conflicting));
+        (0..mkp.number_of_items).for_each(|i| {
+            let sum: u64 = mkp.profit.iter().map(|row| row[i]).sum();
+            assert!(sum.abs_diff(RANGE) <= 1, "sum = {}", sum);
+        });
+    }
+}
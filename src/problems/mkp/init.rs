@@ -0,0 +1,193 @@
+//! 初期集団の生成
+
+use rand::seq::SliceRandom;
+
+use crate::individual::Individual;
+
+use super::MKP;
+
+/// 初期個体の生成方法
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InitStrategy {
+    /// 各ビットを確率0.5で1にする
+    Uniform,
+    /// 各制約の重さの合計の期待値が `容量 × 充填率` 以下になる確率で各ビットを1にする
+    FillRatio(f64),
+    /// ランダムな重みベクトルで利益を重み付けし、正規化した重さとの比の大きい順に詰める
+    GreedyRatio,
+    /// 空のナップザックから、ランダムな順番で入るアイテムを詰める
+    EmptyFill,
+}
+
+/// 一様ランダムに個体を生成する
+fn uniform(mkp: &MKP) -> Individual {
+    (0..mkp.number_of_items)
+        .map(|_| rand::random_range(0..=1))
+        .collect()
+}
+
+/// 充填率を指定してランダムに個体を生成する
+fn fill_ratio(mkp: &MKP, ratio: f64) -> Individual {
+    // 最も厳しい制約で期待値が容量 × 充填率となる確率
+    let prob = mkp
+        .weight
+        .iter()
+        .zip(mkp.capacity.iter())
+        .map(|(w, &c)| {
+            let total_weight: usize = w.iter().sum();
+            if total_weight == 0 {
+                1.0
+            } else {
+                ratio * c as f64 / total_weight as f64
+            }
+        })
+        .fold(1.0, f64::min)
+        .clamp(0.0, 1.0);
+
+    (0..mkp.number_of_items)
+        .map(|_| {
+            let r: f64 = rand::random_range(0.0..1.0);
+            if r < prob { 1 } else { 0 }
+        })
+        .collect()
+}
+
+/// 目的関数の数だけの成分を持つランダムな重みベクトル（合計1）を生成する
+fn random_weight_vector(number_of_obj: usize) -> Vec<f64> {
+    let raw: Vec<f64> = (0..number_of_obj)
+        .map(|_| rand::random_range(0.0..1.0))
+        .collect();
+    let sum: f64 = raw.iter().sum();
+
+    if sum > 0.0 {
+        raw.iter().map(|r| r / sum).collect()
+    } else {
+        vec![1.0 / number_of_obj as f64; number_of_obj]
+    }
+}
+
+/// 指定した順番で、容量を超えない限りアイテムを詰める
+pub(super) fn fill_in_order(mkp: &MKP, order: &[usize]) -> Individual {
+    let mut x: Individual = vec![0; mkp.number_of_items];
+    let mut weights = vec![0; mkp.number_of_constraints()];
+
+    for &item in order {
+        let added: Vec<usize> = weights
+            .iter()
+            .zip(mkp.weight.iter())
+            .map(|(total, w)| total + w[item])
+            .collect();
+        if mkp.is_within_capacity(&added) {
+            x[item] = 1;
+            weights = added;
+        }
+    }
+
+    x
+}
+
+/// 重み付き利益重量比の大きい順に詰めて個体を生成する
+fn greedy_ratio(mkp: &MKP) -> Individual {
+    let lambda = random_weight_vector(mkp.number_of_obj);
+    let scores: Vec<f64> = (0..mkp.number_of_items)
+        .map(|item| {
+            let profit: f64 = lambda
+                .iter()
+                .zip(mkp.profit.iter())
+                .map(|(l, profits)| l * profits[item] as f64)
+                .sum();
+            profit / mkp.normalised_weight(item).max(f64::MIN_POSITIVE)
+        })
+        .collect();
+
+    let mut order: Vec<usize> = (0..mkp.number_of_items).collect();
+    order.sort_by(|&a, &b| scores[b].total_cmp(&scores[a]));
+    fill_in_order(mkp, &order)
+}
+
+/// 空のナップザックからランダムな順番で詰めて個体を生成する
+fn empty_fill(mkp: &MKP) -> Individual {
+    let mut order: Vec<usize> = (0..mkp.number_of_items).collect();
+    order.shuffle(&mut rand::rng());
+    fill_in_order(mkp, &order)
+}
+
+/// 指定した方法で個体を1つ生成する
+pub fn init_individual(mkp: &MKP, strategy: InitStrategy) -> Individual {
+    match strategy {
+        InitStrategy::Uniform => uniform(mkp),
+        InitStrategy::FillRatio(ratio) => fill_ratio(mkp, ratio),
+        InitStrategy::GreedyRatio => greedy_ratio(mkp),
+        InitStrategy::EmptyFill => empty_fill(mkp),
+    }
+}
+
+/// 指定した方法で `n` 個体の初期集団を生成する
+pub fn init_population(
+    mkp: &MKP,
+    strategy: InitStrategy,
+    n: usize,
+) -> Vec<Individual> {
+    (0..n).map(|_| init_individual(mkp, strategy)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::total_weights;
+    use super::*;
+
+    fn create_mkp() -> MKP {
+        MKP {
+            number_of_obj: 2,
+            number_of_items: 6,
+            capacity: vec![12, 10],
+            profit: vec![vec![10, 2, 6, 8, 1, 7], vec![4, 3, 12, 8, 9, 2]],
+            weight: vec![vec![5, 4, 4, 5, 3, 6], vec![2, 3, 6, 1, 4, 3]],
+        }
+    }
+
+    fn is_feasible(mkp: &MKP, x: &Individual) -> bool {
+        mkp.is_within_capacity(&total_weights(mkp, x))
+    }
+
+    #[test]
+    fn test_init_population_size() {
+        let mkp = create_mkp();
+        let population = init_population(&mkp, InitStrategy::Uniform, 10);
+        assert_eq!(population.len(), 10);
+        assert!(population.iter().all(|x| x.len() == 6));
+    }
+
+    #[test]
+    fn test_fill_ratio_zero_is_empty() {
+        let mkp = create_mkp();
+        let x = init_individual(&mkp, InitStrategy::FillRatio(0.0));
+        assert_eq!(x, vec![0; 6]);
+    }
+
+    #[test]
+    fn test_greedy_ratio_is_feasible() {
+        let mkp = create_mkp();
+        let population =
+            init_population(&mkp, InitStrategy::GreedyRatio, 20);
+        assert!(population.iter().all(|x| is_feasible(&mkp, x)));
+    }
+
+    #[test]
+    fn test_empty_fill_is_feasible_and_maximal() {
+        // 容量に空きがあっても、残りのアイテムはどれも入らない
+        let mkp = create_mkp();
+        let population =
+            init_population(&mkp, InitStrategy::EmptyFill, 20);
+        population.iter().for_each(|x| {
+            assert!(is_feasible(&mkp, x));
+            assert!((0..mkp.number_of_items).filter(|&i| x[i] == 0).all(
+                |i| {
+                    let mut added = x.clone();
+                    added[i] = 1;
+                    !is_feasible(&mkp, &added)
+                }
+            ));
+        });
+    }
+}
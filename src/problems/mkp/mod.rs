@@ -0,0 +1,587 @@
+use serde_json::Value;
+use std::{fs, path::Path};
+
+use crate::individual::{Individual, Objectives};
+
+use super::{Problem, Sense};
+
+mod bounds;
+mod delta;
+mod error;
+mod exact;
+mod generator;
+mod init;
+mod pls;
+mod registry;
+mod repair;
+mod validate;
+mod writer;
+mod zitzler_thiele;
+
+pub use bounds::*;
+pub use delta::*;
+pub use error::*;
+pub use exact::*;
+pub use generator::*;
+pub use init::*;
+pub use pls::*;
+pub use registry::*;
+pub use repair::*;
+pub use validate::*;
+pub use writer::*;
+pub use zitzler_thiele::*;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MKP {
+    /// 目的関数の数
+    pub number_of_obj: usize,
+
+    /// アイテムの数
+    pub number_of_items: usize,
+
+    /// 各制約（ナップザック）の容量
+    ///
+    /// 単一容量のJSONファイルは要素数1として読み込む。
+    pub capacity: Vec<usize>,
+
+    /// 各目的関数における各アイテムの利益
+    /// ```
+    /// profit[obj_index][item_index]
+    /// ```
+    /// の場合、目的関数(`obj_index`)におけるアイテム(`item_index`)の利益
+    pub profit: Vec<Vec<u64>>,
+
+    /// 各制約における各アイテムの重さ
+    /// ```
+    /// weight[constraint_index][item_index]
+    /// ```
+    /// の場合、制約(`constraint_index`)におけるアイテム(`item_index`)の重さ
+    pub weight: Vec<Vec<usize>>,
+}
+
+/// 同梱のインスタンスを置くディレクトリ（クレートのルートからの相対パス）
+macro_rules! bundled_dir {
+    () => {
+        "problems/mkp"
+    };
+}
+
+/// 同梱のインスタンスを置くディレクトリ（クレートのルートからの相対パス）
+pub const BUNDLED_DIR: &str = bundled_dir!();
+
+/// 同梱のインスタンスの一覧から [`MKPInstance`] と [`BUNDLED_INSTANCES`] を作る
+///
+/// 列挙子とファイル名は1か所にだけ書き、両者が食い違わないようにする。
+macro_rules! bundled_instances {
+    ($($(#[$doc:meta])* $variant:ident => $name:literal,)*) => {
+        /// 同梱のインスタンス
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum MKPInstance {
+            $($(#[$doc])* $variant,)*
+        }
+
+        /// 同梱のインスタンスのファイル名と内容（[`MKPInstance`] と同じ順）
+        ///
+        /// 実行ファイルに埋め込まれているため、作業ディレクトリに関係なく
+        /// 読み込める。
+        pub const BUNDLED_INSTANCES: &[(&str, &str)] = &[$((
+            $name,
+            include_str!(concat!("../../../", bundled_dir!(), "/", $name)),
+        )),*];
+
+        impl MKPInstance {
+            /// すべての同梱のインスタンス
+            pub fn all() -> &'static [Self] {
+                &[$(Self::$variant),*]
+            }
+        }
+    };
+}
+
+bundled_instances! {
+    /// 目的関数2, アイテム100, インスタンス1
+    P2N100Ins1 => "KP_p-2_n-100_ins-1.json",
+    /// 目的関数2, アイテム20, インスタンス1
+    P2N20Ins1 => "KP_p-2_n-20_ins-1.json",
+    /// 目的関数3, アイテム100, インスタンス1
+    P3N100Ins1 => "KP_p-3_n-100_ins-1.json",
+    /// 目的関数4, アイテム40, インスタンス10
+    P4N40Ins10 => "KP_p-4_n-40_ins-10.json",
+    /// 目的関数5, アイテム20, インスタンス10
+    P5N20Ins10 => "KP_p-5_n-20_ins-10.json",
+}
+
+impl MKPInstance {
+    /// 実行ファイルに埋め込んだインスタンスの内容を取得
+    ///
+    /// 作業ディレクトリに関係なく読み込める。
+    pub fn contents(&self) -> &'static str {
+        BUNDLED_INSTANCES[*self as usize].1
+    }
+
+    /// インスタンスに対応するファイル名を取得
+    pub fn filename(&self) -> &'static str {
+        BUNDLED_INSTANCES[*self as usize].0
+    }
+}
+
+impl std::fmt::Display for MKPInstance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.filename())
+    }
+}
+
+/// JSONのフィールドを取得する
+fn get_field<'a>(
+    json: &'a Value,
+    field: &'static str,
+) -> Result<&'a Value, MkpLoadError> {
+    json.get(field).ok_or_else(|| MkpLoadError::MissingField {
+        field,
+        path: format!("$.{}", field),
+    })
+}
+
+/// JSONの値を非負整数として読み込む
+fn to_u64(
+    value: &Value,
+    field: &'static str,
+    path: &str,
+) -> Result<u64, MkpLoadError> {
+    value.as_u64().ok_or_else(|| MkpLoadError::InvalidType {
+        field,
+        path: path.to_string(),
+        expected: "unsigned integer",
+    })
+}
+
+/// JSONの値を配列として読み込む
+fn to_array<'a>(
+    value: &'a Value,
+    field: &'static str,
+    path: &str,
+) -> Result<&'a Vec<Value>, MkpLoadError> {
+    value.as_array().ok_or_else(|| MkpLoadError::InvalidType {
+        field,
+        path: path.to_string(),
+        expected: "array",
+    })
+}
+
+/// JSONの値を非負整数の配列として読み込む
+fn to_u64_row(
+    value: &Value,
+    field: &'static str,
+    path: &str,
+) -> Result<Vec<u64>, MkpLoadError> {
+    to_array(value, field, path)?
+        .iter()
+        .enumerate()
+        .map(|(i, v)| to_u64(v, field, &format!("{}[{}]", path, i)))
+        .collect()
+}
+
+/// JSONの値を非負整数の2次元配列として読み込む
+fn to_u64_matrix(
+    value: &Value,
+    field: &'static str,
+    path: &str,
+) -> Result<Vec<Vec<u64>>, MkpLoadError> {
+    to_array(value, field, path)?
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            to_u64_row(row, field, &format!("{}[{}]", path, i))
+        })
+        .collect()
+}
+
+/// `u64` の配列を `usize` の配列に変換する
+fn to_usize_row(row: Vec<u64>) -> Vec<usize> {
+    row.into_iter().map(|n| n as usize).collect()
+}
+
+impl MKP {
+    /// インスタンスから問題を読み込む
+    ///
+    /// 埋め込んだ内容から読み込むため、作業ディレクトリに依存しない。
+    pub fn from_instance(
+        instance: MKPInstance,
+    ) -> Result<Self, MkpLoadError> {
+        Self::from_json_str(instance.contents())
+    }
+
+    /// ファイルパスから問題を読み込む
+    ///
+    /// 退化したデータについての警告は破棄する。
+    pub fn from_file(path: &Path) -> Result<Self, MkpLoadError> {
+        Self::from_file_with_warnings(path).map(|(mkp, _)| mkp)
+    }
+
+    /// ファイルパスから問題を読み込み、検証で得られた警告とともに返す
+    pub fn from_file_with_warnings(
+        path: &Path,
+    ) -> Result<(Self, Vec<MkpWarning>), MkpLoadError> {
+        let content =
+            fs::read_to_string(path).map_err(|e| MkpLoadError::Io {
+                path: path.to_path_buf(),
+                source: e,
+            })?;
+        Self::from_json_str_with_warnings(&content)
+    }
+
+    /// JSON文字列から問題を読み込む
+    ///
+    /// 退化したデータについての警告は破棄する。
+    pub fn from_json_str(content: &str) -> Result<Self, MkpLoadError> {
+        Self::from_json_str_with_warnings(content).map(|(mkp, _)| mkp)
+    }
+
+    /// JSON文字列から問題を読み込み、検証で得られた警告とともに返す
+    pub fn from_json_str_with_warnings(
+        content: &str,
+    ) -> Result<(Self, Vec<MkpWarning>), MkpLoadError> {
+        let json: Value = serde_json::from_str(content)?;
+
+        Self::from_json_value(&json)
+    }
+
+    /// 読み込んだインスタンスを検証し、警告とともに返す
+    fn validate_on_load(
+        self,
+    ) -> Result<(Self, Vec<MkpWarning>), MkpLoadError> {
+        let warnings = self.validate()?;
+        Ok((self, warnings))
+    }
+
+    /// JSONのValueからMKPを構築する
+    fn from_json_value(
+        json: &Value,
+    ) -> Result<(Self, Vec<MkpWarning>), MkpLoadError> {
+        let number_of_obj = to_u64(
+            get_field(json, "number_of_obj")?,
+            "number_of_obj",
+            "$.number_of_obj",
+        )? as usize;
+        let number_of_items = to_u64(
+            get_field(json, "number_of_items")?,
+            "number_of_items",
+            "$.number_of_items",
+        )? as usize;
+
+        // 単一容量の場合は数値、複数容量の場合は配列
+        let capacity_value = get_field(json, "capacity")?;
+        let capacity = if capacity_value.is_array() {
+            to_usize_row(to_u64_row(
+                capacity_value,
+                "capacity",
+                "$.capacity",
+            )?)
+        } else {
+            vec![to_u64(capacity_value, "capacity", "$.capacity")? as usize]
+        };
+
+        let profit = to_u64_matrix(
+            get_field(json, "profit")?,
+            "profit",
+            "$.profit",
+        )?;
+
+        // 単一容量の場合は1次元配列、複数容量の場合は2次元配列
+        let weight_value = get_field(json, "weight")?;
+        let is_matrix = to_array(weight_value, "weight", "$.weight")?
+            .first()
+            .is_some_and(Value::is_array);
+        let weight: Vec<Vec<usize>> = if is_matrix {
+            to_u64_matrix(weight_value, "weight", "$.weight")?
+                .into_iter()
+                .map(to_usize_row)
+                .collect()
+        } else {
+            vec![to_usize_row(to_u64_row(
+                weight_value,
+                "weight",
+                "$.weight",
+            )?)]
+        };
+
+        Self {
+            number_of_obj,
+            number_of_items,
+            capacity,
+            profit,
+            weight,
+        }
+        .validate_on_load()
+    }
+}
+
+impl MKP {
+    /// 制約（ナップザック）の数
+    pub fn number_of_constraints(&self) -> usize {
+        self.capacity.len()
+    }
+
+    /// 各制約の重さの合計が容量以下の場合true
+    pub fn is_within_capacity(&self, weights: &[usize]) -> bool {
+        weights
+            .iter()
+            .zip(self.capacity.iter())
+            .all(|(w, c)| w <= c)
+    }
+
+    /// 容量で正規化したアイテムの重さ
+    ///
+    /// 各制約における `weight / capacity` の合計とする。
+    fn normalised_weight(&self, item: usize) -> f64 {
+        self.weight
+            .iter()
+            .zip(self.capacity.iter())
+            .map(|(w, &c)| w[item] as f64 / c.max(1) as f64)
+            .sum()
+    }
+}
+
+/// 各制約における重さの合計を計算する
+pub fn total_weights(mkp: &MKP, x: &Individual) -> Vec<usize> {
+    mkp.weight
+        .iter()
+        .map(|w| {
+            x.iter()
+                .zip(w.iter())
+                .map(|(&bit, &w_i)| w_i * bit as usize)
+                .sum()
+        })
+        .collect()
+}
+
+/// 各制約の容量超過量を計算する
+///
+/// 制約を満たしている次元は0となる。
+pub fn constraint_violation(mkp: &MKP, x: &Individual) -> Vec<usize> {
+    total_weights(mkp, x)
+        .iter()
+        .zip(mkp.capacity.iter())
+        .map(|(&w, &c)| w.saturating_sub(c))
+        .collect()
+}
+
+/// 各目的関数の利益の合計を計算する
+pub fn total_profits(mkp: &MKP, x: &Individual) -> Vec<u64> {
+    mkp.profit
+        .iter()
+        .map(|p| {
+            x.iter()
+                .zip(p.iter())
+                .filter(|&(&bit, _)| bit == 1)
+                .map(|(_, &p_i)| p_i)
+                .sum()
+        })
+        .collect()
+}
+
+/// 利益と重さの合計から目的関数値を求める
+fn penalised_objectives(
+    mkp: &MKP,
+    profits: &[u64],
+    weights: &[usize],
+) -> Objectives {
+    // 容量制約違反のペナルティ
+    let violation: usize = weights
+        .iter()
+        .zip(mkp.capacity.iter())
+        .map(|(&w, &c)| w.saturating_sub(c))
+        .sum();
+    if violation > 0 {
+        // 制約違反時は、すべての目的関数値を -(各制約の超過量の合計) に設定
+        let penalty = -(violation as f64);
+        vec![penalty; mkp.number_of_obj]
+    } else {
+        profits.iter().map(|&p| p as f64).collect()
+    }
+}
+
+/// 適合度を計算する
+pub fn fit_mkp(mkp: &MKP, x: &Individual) -> Objectives {
+    penalised_objectives(
+        mkp,
+        &total_profits(mkp, x),
+        &total_weights(mkp, x),
+    )
+}
+
+impl Problem for MKP {
+    type Solution = Individual;
+
+    fn number_of_objectives(&self) -> usize {
+        self.number_of_obj
+    }
+
+    fn senses(&self) -> Vec<Sense> {
+        vec![Sense::Maximize; self.number_of_obj]
+    }
+
+    /// 各目的関数の利益の合計（容量制約違反のペナルティは含めない）
+    fn evaluate(&self, x: &Self::Solution) -> Objectives {
+        total_profits(self, x).iter().map(|&p| p as f64).collect()
+    }
+
+    fn number_of_constraints(&self) -> usize {
+        self.capacity.len()
+    }
+
+    fn constraint_violation(&self, x: &Self::Solution) -> Vec<f64> {
+        constraint_violation(self, x)
+            .iter()
+            .map(|&v| v as f64)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_json_value_single_capacity() {
+        // 単一容量の形式は制約数1として読み込まれる
+        let json: Value = serde_json::from_str(
+            r#"{
+                "number_of_obj": 2,
+                "number_of_items": 3,
+                "capacity": 10,
+                "profit": [[1, 2, 3], [4, 5, 6]],
+                "weight": [3, 4, 5]
+            }"#,
+        )
+        .unwrap();
+        let mkp = MKP::from_json_value(&json).unwrap().0;
+        assert_eq!(mkp.capacity, vec![10]);
+        assert_eq!(mkp.weight, vec![vec![3, 4, 5]]);
+    }
+
+    #[test]
+    fn test_from_instance_bundled_files() {
+        // 同梱の単一容量のファイルがすべて読み込める
+        MKPInstance::all().iter().for_each(|&instance| {
+            let mkp = MKP::from_instance(instance).unwrap();
+            assert_eq!(mkp.number_of_constraints(), 1);
+            assert_eq!(mkp.weight[0].len(), mkp.number_of_items);
+        });
+    }
+
+    #[test]
+    fn test_from_json_value_multiple_capacities() {
+        let json: Value = serde_json::from_str(
+            r#"{
+                "number_of_obj": 2,
+                "number_of_items": 3,
+                "capacity": [10, 8],
+                "profit": [[1, 2, 3], [4, 5, 6]],
+                "weight": [[3, 4, 5], [6, 1, 2]]
+            }"#,
+        )
+        .unwrap();
+        let mkp = MKP::from_json_value(&json).unwrap().0;
+        assert_eq!(mkp.number_of_constraints(), 2);
+        assert_eq!(mkp.capacity, vec![10, 8]);
+        assert_eq!(mkp.weight, vec![vec![3, 4, 5], vec![6, 1, 2]]);
+    }
+
+    #[test]
+    fn test_from_json_value_missing_field() {
+        let json: Value = serde_json::from_str(
+            r#"{"number_of_obj": 2, "number_of_items": 3, "capacity": 10}"#,
+        )
+        .unwrap();
+        match MKP::from_json_value(&json) {
+            Err(MkpLoadError::MissingField { field, path }) => {
+                assert_eq!(field, "profit");
+                assert_eq!(path, "$.profit");
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_from_json_value_invalid_type_reports_path() {
+        let json: Value = serde_json::from_str(
+            r#"{
+                "number_of_obj": 2,
+                "number_of_items": 3,
+                "capacity": 10,
+                "profit": [[1, 2, 3], [4, -5, 6]],
+                "weight": [3, 4, 5]
+            }"#,
+        )
+        .unwrap();
+        match MKP::from_json_value(&json) {
+            Err(MkpLoadError::InvalidType { field, path, .. }) => {
+                assert_eq!(field, "profit");
+                assert_eq!(path, "$.profit[1][1]");
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_from_json_value_weight_rows_mismatch() {
+        // 容量の数と重さの行数が一致しない
+        let json: Value = serde_json::from_str(
+            r#"{
+                "number_of_obj": 2,
+                "number_of_items": 3,
+                "capacity": [10, 8, 5],
+                "profit": [[1, 2, 3], [4, 5, 6]],
+                "weight": [[3, 4, 5], [6, 1, 2]]
+            }"#,
+        )
+        .unwrap();
+        match MKP::from_json_value(&json) {
+            Err(MkpLoadError::DimensionMismatch {
+                field,
+                expected,
+                found,
+                ..
+            }) => {
+                assert_eq!(field, "weight");
+                assert_eq!(expected, 3);
+                assert_eq!(found, 2);
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_constraint_violation_per_dimension() {
+        let mkp = MKP {
+            number_of_obj: 2,
+            number_of_items: 3,
+            capacity: vec![10, 8],
+            profit: vec![vec![1, 2, 3], vec![4, 5, 6]],
+            weight: vec![vec![3, 4, 5], vec![6, 1, 2]],
+        };
+        // 重さ: [12, 9] → 各制約で2, 1超過
+        assert_eq!(constraint_violation(&mkp, &vec![1, 1, 1]), vec![2, 1]);
+        assert_eq!(fit_mkp(&mkp, &vec![1, 1, 1]), vec![-3.0, -3.0]);
+        assert_eq!(fit_mkp(&mkp, &vec![0, 1, 1]), vec![5.0, 11.0]);
+    }
+
+    #[test]
+    fn test_problem_impl() {
+        // Problem としての評価ではペナルティを含めず、違反量を別に返す
+        let mkp = MKP {
+            number_of_obj: 2,
+            number_of_items: 3,
+            capacity: vec![10, 8],
+            profit: vec![vec![1, 2, 3], vec![4, 5, 6]],
+            weight: vec![vec![3, 4, 5], vec![6, 1, 2]],
+        };
+        let x = vec![1, 1, 1];
+        assert_eq!(mkp.senses(), vec![Sense::Maximize; 2]);
+        assert_eq!(mkp.evaluate(&x), vec![6.0, 15.0]);
+        assert_eq!(mkp.evaluate_minimised(&x), vec![-6.0, -15.0]);
+        assert_eq!(mkp.number_of_constraints(), 2);
+        assert_eq!(mkp.constraint_violation(&x), vec![2.0, 1.0]);
+    }
+}
@@ -0,0 +1,302 @@
+//! パレート局所探索（Pareto Local Search, PLS）
+//!
+//! アーカイブ中の未探索の解について近傍を調べ、アーカイブに支配されない近傍解を
+//! アーカイブに加えることを繰り返す。近傍解の評価は [`MkpEvalState`] による
+//! 差分評価で行う。
+
+use crate::individual::Individual;
+use crate::moea::ImprovementStep;
+
+use super::{MKP, MkpEvalState};
+
+/// 探索する近傍
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Neighbourhood {
+    /// 1ビットを反転する近傍
+    OneFlip,
+    /// 入っているアイテムと入っていないアイテムを1つずつ入れ換える近傍
+    Swap,
+    /// 1ビット反転近傍と入れ換え近傍の両方
+    Both,
+}
+
+/// PLSの設定
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlsConfig {
+    /// 探索する近傍
+    pub neighbourhood: Neighbourhood,
+    /// 近傍を調べるアーカイブ解の最大数
+    pub max_explorations: usize,
+}
+
+impl Default for PlsConfig {
+    fn default() -> Self {
+        Self {
+            neighbourhood: Neighbourhood::Both,
+            max_explorations: 100,
+        }
+    }
+}
+
+/// アーカイブに格納する解
+#[derive(Debug, Clone)]
+struct ArchiveEntry {
+    state: MkpEvalState,
+    /// 近傍を調べ終えたか
+    explored: bool,
+}
+
+impl ArchiveEntry {
+    fn new(state: MkpEvalState) -> Self {
+        Self {
+            state,
+            explored: false,
+        }
+    }
+}
+
+/// 利益の最大化において `a` が `b` を弱支配する場合true
+pub(super) fn weakly_dominates(a: &[u64], b: &[u64]) -> bool {
+    a.iter()
+        .zip(b.iter())
+        .all(|(a_elem, b_elem)| a_elem >= b_elem)
+}
+
+/// 近傍解の移動
+///
+/// 反転するアイテムの番号の列で表す。
+type Move = Vec<usize>;
+
+/// 解の近傍への移動を列挙する
+fn neighbour_moves(
+    x: &Individual,
+    neighbourhood: Neighbourhood,
+) -> Vec<Move> {
+    let (ins, outs): (Vec<usize>, Vec<usize>) =
+        (0..x.len()).partition(|&i| x[i] == 1);
+
+    let flips = ins.iter().chain(outs.iter()).map(|&i| vec![i]);
+    let swaps = ins
+        .iter()
+        .flat_map(|&i| outs.iter().map(move |&j| vec![i, j]));
+
+    match neighbourhood {
+        Neighbourhood::OneFlip => flips.collect(),
+        Neighbourhood::Swap => swaps.collect(),
+        Neighbourhood::Both => flips.chain(swaps).collect(),
+    }
+}
+
+/// 移動後の評価状態を返す
+///
+/// 容量制約を満たさない場合は `None` を返す。
+fn apply_move(
+    mkp: &MKP,
+    state: &MkpEvalState,
+    items: &[usize],
+) -> Option<MkpEvalState> {
+    if !mkp.is_within_capacity(&state.weights_after_flips(mkp, items)) {
+        return None;
+    }
+    let mut moved = state.clone();
+    items.iter().for_each(|&item| moved.flip(mkp, item));
+    Some(moved)
+}
+
+/// 解をアーカイブに加える
+///
+/// アーカイブ中のいずれかの解に弱支配される場合は加えずfalseを返す。
+/// 加えた場合は、新しい解に支配される解をアーカイブから取り除く。
+fn try_insert(
+    archive: &mut Vec<ArchiveEntry>,
+    entry: ArchiveEntry,
+) -> bool {
+    if archive.iter().any(|a| {
+        weakly_dominates(a.state.profits(), entry.state.profits())
+    }) {
+        return false;
+    }
+
+    archive.retain(|a| {
+        !weakly_dominates(entry.state.profits(), a.state.profits())
+    });
+    archive.push(entry);
+    true
+}
+
+/// パレート局所探索を行い、得られた非支配解の集合を返す
+///
+/// `initial` のうち容量制約を満たす解からアーカイブを作り、
+/// 未探索の解がなくなるか、`config.max_explorations` 個の解の近傍を
+/// 調べ終えるまで探索を続ける。
+pub fn pareto_local_search(
+    mkp: &MKP,
+    initial: &[Individual],
+    config: &PlsConfig,
+) -> Vec<Individual> {
+    let mut archive: Vec<ArchiveEntry> = Vec::new();
+    initial
+        .iter()
+        .map(|x| MkpEvalState::new(mkp, x.clone()))
+        .filter(|state| state.is_feasible(mkp))
+        .for_each(|state| {
+            try_insert(&mut archive, ArchiveEntry::new(state));
+        });
+
+    for _ in 0..config.max_explorations {
+        let Some(current) = archive.iter().position(|a| !a.explored)
+        else {
+            break;
+        };
+        archive[current].explored = true;
+        let state = archive[current].state.clone();
+
+        for mv in neighbour_moves(state.x(), config.neighbourhood) {
+            if let Some(moved) = apply_move(mkp, &state, &mv) {
+                try_insert(&mut archive, ArchiveEntry::new(moved));
+            }
+        }
+    }
+
+    archive
+        .into_iter()
+        .map(|entry| entry.state.into_individual())
+        .collect()
+}
+
+/// NSGA-IIの各世代で適用する改善ステップ
+///
+/// 集団にPLSを適用し、元の集団にPLSで得られた新しい解を加えて返す。
+/// 集団の大きさは増えるため、続く環境選択で元の大きさに戻すことを想定する。
+/// 世代ループからは [`PlsImprovement`] を通して呼び出す。
+pub fn pls_improvement_step(
+    mkp: &MKP,
+    population: &[Individual],
+    config: &PlsConfig,
+) -> Vec<Individual> {
+    let improved = pareto_local_search(mkp, population, config);
+
+    let mut result = population.to_vec();
+    result
+        .extend(improved.into_iter().filter(|x| !population.contains(x)));
+    result
+}
+
+/// PLSによる改善ステップ
+///
+/// [`ImprovementStep`] として
+/// [`environmental_selection`](crate::moea::environmental_selection) に渡す。
+#[derive(Debug, Clone, Copy)]
+pub struct PlsImprovement<'a> {
+    pub mkp: &'a MKP,
+    pub config: PlsConfig,
+}
+
+impl ImprovementStep<Individual> for PlsImprovement<'_> {
+    fn improve(&mut self, population: &[Individual]) -> Vec<Individual> {
+        pls_improvement_step(self.mkp, population, &self.config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_mkp() -> MKP {
+        MKP {
+            number_of_obj: 2,
+            number_of_items: 5,
+            capacity: vec![10],
+            profit: vec![vec![10, 2, 6, 8, 1], vec![4, 3, 12, 8, 9]],
+            weight: vec![vec![5, 4, 4, 5, 3]],
+        }
+    }
+
+    /// 全探索で非支配解の利益ベクトルを求める
+    fn brute_force_front(mkp: &MKP) -> Vec<Vec<u64>> {
+        let subsets = 1usize << mkp.number_of_items;
+        let mut archive = Vec::new();
+        (0..subsets)
+            .map(|bits| {
+                let x = (0..mkp.number_of_items)
+                    .map(|i| ((bits >> i) & 1) as u8)
+                    .collect();
+                MkpEvalState::new(mkp, x)
+            })
+            .filter(|state| state.is_feasible(mkp))
+            .for_each(|state| {
+                try_insert(&mut archive, ArchiveEntry::new(state));
+            });
+        archive
+            .into_iter()
+            .map(|entry| entry.state.profits().to_vec())
+            .collect()
+    }
+
+    #[test]
+    fn test_neighbour_moves_count() {
+        // 1ビット反転: 5通り, 入れ換え: 2 × 3 = 6通り
+        let x = vec![1, 0, 1, 0, 0];
+        assert_eq!(neighbour_moves(&x, Neighbourhood::OneFlip).len(), 5);
+        assert_eq!(neighbour_moves(&x, Neighbourhood::Swap).len(), 6);
+        assert_eq!(neighbour_moves(&x, Neighbourhood::Both).len(), 11);
+    }
+
+    #[test]
+    fn test_apply_move_matches_full_evaluation() {
+        let mkp = create_mkp();
+        let state = MkpEvalState::new(&mkp, vec![1, 0, 0, 0, 1]);
+        let moved = apply_move(&mkp, &state, &[0, 2]).unwrap();
+        assert_eq!(moved, MkpEvalState::new(&mkp, vec![0, 0, 1, 0, 1]));
+    }
+
+    #[test]
+    fn test_apply_move_infeasible() {
+        // 重さ 8 + 5 = 13 > 容量10
+        let mkp = create_mkp();
+        let state = MkpEvalState::new(&mkp, vec![1, 0, 0, 0, 1]);
+        assert!(apply_move(&mkp, &state, &[3]).is_none());
+    }
+
+    #[test]
+    fn test_pareto_local_search_finds_front() {
+        let mkp = create_mkp();
+        let config = PlsConfig::default();
+        let result = pareto_local_search(&mkp, &[vec![0; 5]], &config);
+
+        let mut found: Vec<Vec<u64>> = result
+            .iter()
+            .map(|x| MkpEvalState::new(&mkp, x.clone()).profits().to_vec())
+            .collect();
+        let mut expected = brute_force_front(&mkp);
+        found.sort();
+        expected.sort();
+        assert_eq!(found, expected);
+    }
+
+    #[test]
+    fn test_pls_improvement_step_keeps_population() {
+        let mkp = create_mkp();
+        let population = vec![vec![0; 5], vec![1, 1, 1, 1, 1]];
+        let result =
+            pls_improvement_step(&mkp, &population, &PlsConfig::default());
+        assert_eq!(result[..2], population[..]);
+        assert!(result.len() > population.len());
+    }
+
+    #[test]
+    fn test_pls_improvement_as_hook() {
+        // 世代ループからはトレイトオブジェクトとして呼び出せる
+        let mkp = create_mkp();
+        let population = vec![vec![0; 5]];
+        let mut step: Box<dyn ImprovementStep<Individual>> =
+            Box::new(PlsImprovement {
+                mkp: &mkp,
+                config: PlsConfig::default(),
+            });
+        assert_eq!(
+            step.improve(&population),
+            pls_improvement_step(&mkp, &population, &PlsConfig::default())
+        );
+    }
+}
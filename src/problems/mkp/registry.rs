@@ -0,0 +1,253 @@
+//! インスタンスの一覧
+//!
+//! ディレクトリを走査して `KP_p-{p}_n-{n}_ins-{k}.json` 形式のファイルを集め、
+//! ファイル名から目的関数の数・アイテムの数・インスタンス番号を読み取る。
+//! 同梱のインスタンスは実行ファイルに埋め込まれているため、作業ディレクトリに
+//! 関係なく利用できる。
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use super::{BUNDLED_INSTANCES, MKP, MkpLoadError};
+
+/// インスタンスのディレクトリを指定する環境変数
+pub const MKP_DIR_ENV: &str = "NSGAII_MKP_DIR";
+
+/// インスタンスの読み込み元
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InstanceSource {
+    /// 実行ファイルに埋め込んだ内容
+    Embedded(&'static str),
+    /// ファイル
+    File(PathBuf),
+}
+
+/// インスタンスのメタデータ
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstanceMeta {
+    /// 拡張子を除いたファイル名（例: `KP_p-2_n-20_ins-1`）
+    pub name: String,
+    /// 目的関数の数
+    pub number_of_obj: usize,
+    /// アイテムの数
+    pub number_of_items: usize,
+    /// インスタンス番号
+    pub instance: usize,
+    /// 読み込み元
+    pub source: InstanceSource,
+}
+
+impl InstanceMeta {
+    /// インスタンスを読み込む
+    pub fn load(&self) -> Result<MKP, MkpLoadError> {
+        match &self.source {
+            InstanceSource::Embedded(content) => {
+                MKP::from_json_str(content)
+            }
+            InstanceSource::File(path) => MKP::from_file(path),
+        }
+    }
+}
+
+/// ファイル名から `(目的関数の数, アイテムの数, インスタンス番号)` を読み取る
+///
+/// 拡張子 `.json` は省略できる。
+pub fn parse_instance_name(name: &str) -> Option<(usize, usize, usize)> {
+    let stem = name.strip_suffix(".json").unwrap_or(name);
+    let mut parts = stem.strip_prefix("KP_")?.split('_');
+    let mut field = |key: &str| {
+        parts
+            .next()?
+            .strip_prefix(key)?
+            .strip_prefix('-')?
+            .parse()
+            .ok()
+    };
+
+    let parsed = (field("p")?, field("n")?, field("ins")?);
+    parts.next().is_none().then_some(parsed)
+}
+
+/// インスタンスの一覧
+#[derive(Debug, Clone, Default)]
+pub struct InstanceRegistry {
+    entries: Vec<InstanceMeta>,
+}
+
+impl InstanceRegistry {
+    /// 同梱のインスタンスの一覧
+    ///
+    /// [`BUNDLED_INSTANCES`] の表から作る。
+    pub fn bundled() -> Self {
+        let entries = BUNDLED_INSTANCES
+            .iter()
+            .filter_map(|&(name, content)| {
+                parse_instance_name(name).map(|(p, n, ins)| InstanceMeta {
+                    name: name.trim_end_matches(".json").to_string(),
+                    number_of_obj: p,
+                    number_of_items: n,
+                    instance: ins,
+                    source: InstanceSource::Embedded(content),
+                })
+            })
+            .collect();
+
+        Self::sorted(entries)
+    }
+
+    /// ディレクトリを走査してインスタンスの一覧を作る
+    ///
+    /// 命名規則に従わないファイルは無視する。
+    pub fn from_dir(dir: &Path) -> Result<Self, MkpLoadError> {
+        let io_error = |e| MkpLoadError::Io {
+            path: dir.to_path_buf(),
+            source: e,
+        };
+        let entries = fs::read_dir(dir)
+            .map_err(io_error)?
+            .map(|entry| entry.map(|e| e.path()).map_err(io_error))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .filter(|path| {
+                path.extension().is_some_and(|ext| ext == "json")
+            })
+            .filter_map(|path| {
+                let name = path.file_stem()?.to_str()?.to_string();
+                let (p, n, ins) = parse_instance_name(&name)?;
+                Some(InstanceMeta {
+                    name,
+                    number_of_obj: p,
+                    number_of_items: n,
+                    instance: ins,
+                    source: InstanceSource::File(path),
+                })
+            })
+            .collect();
+
+        Ok(Self::sorted(entries))
+    }
+
+    /// 環境変数 `NSGAII_MKP_DIR` のディレクトリからインスタンスの一覧を作る
+    ///
+    /// 環境変数が設定されていない場合は同梱のインスタンスの一覧を返す。
+    pub fn from_env() -> Result<Self, MkpLoadError> {
+        match env::var_os(MKP_DIR_ENV) {
+            Some(dir) => Self::from_dir(Path::new(&dir)),
+            None => Ok(Self::bundled()),
+        }
+    }
+
+    /// 目的関数の数・アイテムの数・インスタンス番号の順に並べる
+    fn sorted(mut entries: Vec<InstanceMeta>) -> Self {
+        entries.sort_by_key(|meta| {
+            (meta.number_of_obj, meta.number_of_items, meta.instance)
+        });
+        Self { entries }
+    }
+
+    /// すべてのインスタンス
+    pub fn iter(&self) -> impl Iterator<Item = &InstanceMeta> {
+        self.entries.iter()
+    }
+
+    /// インスタンスの数
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// インスタンスがない場合true
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// 名前でインスタンスを探す
+    ///
+    /// 拡張子 `.json` は省略できる。
+    pub fn get(&self, name: &str) -> Option<&InstanceMeta> {
+        let stem = name.strip_suffix(".json").unwrap_or(name);
+        self.entries.iter().find(|meta| meta.name == stem)
+    }
+
+    /// 属性でインスタンスを絞り込む
+    ///
+    /// `None` を指定した属性は絞り込みに使わない。
+    pub fn find(
+        &self,
+        number_of_obj: Option<usize>,
+        number_of_items: Option<usize>,
+        instance: Option<usize>,
+    ) -> Vec<&InstanceMeta> {
+        let matches = |expected: Option<usize>, value| {
+            expected.is_none_or(|e| e == value)
+        };
+        self.entries
+            .iter()
+            .filter(|meta| {
+                matches(number_of_obj, meta.number_of_obj)
+                    && matches(number_of_items, meta.number_of_items)
+                    && matches(instance, meta.instance)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::BUNDLED_DIR;
+    use super::*;
+
+    #[test]
+    fn test_parse_instance_name() {
+        assert_eq!(
+            parse_instance_name("KP_p-4_n-40_ins-10.json"),
+            Some((4, 40, 10))
+        );
+        assert_eq!(
+            parse_instance_name("KP_p-2_n-20_ins-1"),
+            Some((2, 20, 1))
+        );
+        assert_eq!(parse_instance_name("KP_p-2_n-20.json"), None);
+        assert_eq!(parse_instance_name("knapsack.100.2"), None);
+        assert_eq!(parse_instance_name("KP_p-2_n-20_ins-1_x.json"), None);
+    }
+
+    #[test]
+    fn test_bundled() {
+        let registry = InstanceRegistry::bundled();
+        assert_eq!(registry.len(), BUNDLED_INSTANCES.len());
+        registry.iter().for_each(|meta| {
+            let mkp = meta.load().unwrap();
+            assert_eq!(mkp.number_of_obj, meta.number_of_obj);
+            assert_eq!(mkp.number_of_items, meta.number_of_items);
+        });
+    }
+
+    #[test]
+    fn test_get_and_find() {
+        let registry = InstanceRegistry::bundled();
+        let meta = registry.get("KP_p-2_n-20_ins-1.json").unwrap();
+        assert_eq!(meta.number_of_items, 20);
+        assert!(registry.get("KP_p-9_n-9_ins-9").is_none());
+
+        let two_obj = registry.find(Some(2), None, None);
+        assert_eq!(two_obj.len(), 2);
+        let ins10 = registry.find(None, None, Some(10));
+        assert_eq!(ins10.len(), 2);
+    }
+
+    #[test]
+    fn test_from_dir_matches_bundled() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join(BUNDLED_DIR);
+        let registry = InstanceRegistry::from_dir(&dir).unwrap();
+        let bundled = InstanceRegistry::bundled();
+        assert_eq!(registry.len(), bundled.len());
+        registry.iter().zip(bundled.iter()).for_each(
+            |(file, embedded)| {
+                assert_eq!(file.name, embedded.name);
+                assert_eq!(file.load().unwrap(), embedded.load().unwrap());
+            },
+        );
+    }
+}
@@ -0,0 +1,182 @@
+//! 実行不可能解の修復
+
+use crate::individual::{Individual, Objectives};
+
+use super::{MKP, fit_mkp, total_weights};
+
+/// 修復結果を遺伝子に反映するかどうか
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RepairMode {
+    /// 修復後の遺伝子を個体に書き戻す（ラマルク型）
+    Lamarckian,
+    /// 修復後の遺伝子で評価だけ行い、個体は元のまま残す（ボールドウィン型）
+    Baldwinian,
+}
+
+/// アイテムの利益重量比
+///
+/// Zitzler–Thiele に従い、全目的関数における `profit / weight` の最大値を用いる。
+/// 目的関数と制約の数が等しい場合は、目的関数 `k` の利益を制約 `k` の重さで割る。
+/// それ以外の場合は、容量で正規化した重さの合計で割る。
+/// 重さが0のアイテムは取り除いても容量が空かないため、無限大とする。
+fn profit_weight_ratio(mkp: &MKP, item: usize) -> f64 {
+    let paired = mkp.number_of_constraints() == mkp.number_of_obj;
+
+    mkp.profit
+        .iter()
+        .enumerate()
+        .map(|(k, profits)| {
+            let weight = if paired {
+                mkp.weight[k][item] as f64
+            } else {
+                mkp.normalised_weight(item)
+            };
+            if weight == 0.0 {
+                f64::INFINITY
+            } else {
+                profits[item] as f64 / weight
+            }
+        })
+        .fold(f64::NEG_INFINITY, f64::max)
+}
+
+/// アイテムを取り除く順番を求める
+///
+/// 利益重量比の小さい（効率の悪い）アイテムから順に並べる。
+pub fn removal_order(mkp: &MKP) -> Vec<usize> {
+    let ratios: Vec<f64> = (0..mkp.number_of_items)
+        .map(|item| profit_weight_ratio(mkp, item))
+        .collect();
+
+    let mut order: Vec<usize> = (0..mkp.number_of_items).collect();
+    order.sort_by(|&a, &b| ratios[a].total_cmp(&ratios[b]));
+    order
+}
+
+/// 貪欲法で実行不可能解を修復する
+///
+/// すべての容量制約を満たすまで、利益重量比の小さいアイテムから順に取り除く。
+/// 元から実行可能な解はそのまま返す。
+pub fn repair_mkp(mkp: &MKP, x: &Individual) -> Individual {
+    let mut repaired = x.clone();
+    let mut weights = total_weights(mkp, &repaired);
+
+    for item in removal_order(mkp) {
+        if mkp.is_within_capacity(&weights) {
+            break;
+        }
+        if item < repaired.len() && repaired[item] == 1 {
+            repaired[item] = 0;
+            weights
+                .iter_mut()
+                .zip(mkp.weight.iter())
+                .for_each(|(total, w)| *total -= w[item]);
+        }
+    }
+
+    repaired
+}
+
+/// 修復してから適合度を計算する
+///
+/// 戻り値の遺伝子は `mode` によって異なる。
+/// - [`RepairMode::Lamarckian`] の場合は修復後の遺伝子
+/// - [`RepairMode::Baldwinian`] の場合は元の遺伝子
+///
+/// いずれの場合も目的関数値は修復後の遺伝子で計算する。
+pub fn fit_mkp_with_repair(
+    mkp: &MKP,
+    x: &Individual,
+    mode: RepairMode,
+) -> (Individual, Objectives) {
+    let repaired = repair_mkp(mkp, x);
+    let objectives = fit_mkp(mkp, &repaired);
+
+    match mode {
+        RepairMode::Lamarckian => (repaired, objectives),
+        RepairMode::Baldwinian => (x.clone(), objectives),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_mkp() -> MKP {
+        MKP {
+            number_of_obj: 2,
+            number_of_items: 4,
+            capacity: vec![10],
+            profit: vec![vec![10, 2, 6, 8], vec![4, 3, 12, 8]],
+            // 利益重量比: [2.0, 0.75, 3.0, 1.6]
+            weight: vec![vec![5, 4, 4, 5]],
+        }
+    }
+
+    #[test]
+    fn test_removal_order() {
+        let mkp = create_mkp();
+        assert_eq!(removal_order(&mkp), vec![1, 3, 0, 2]);
+    }
+
+    #[test]
+    fn test_repair_feasible_unchanged() {
+        // 実行可能解は変更されない
+        let mkp = create_mkp();
+        let x = vec![1, 0, 1, 0];
+        assert_eq!(repair_mkp(&mkp, &x), x);
+    }
+
+    #[test]
+    fn test_repair_removes_worst_ratio_first() {
+        // 重さ18 > 容量10 のため、比の小さいアイテム1, 3を取り除く
+        let mkp = create_mkp();
+        let x = vec![1, 1, 1, 1];
+        assert_eq!(repair_mkp(&mkp, &x), vec![1, 0, 1, 0]);
+    }
+
+    #[test]
+    fn test_repair_stops_when_feasible() {
+        // アイテム1を取り除いた時点で重さ10 <= 容量10 となる
+        let mkp = create_mkp();
+        let x = vec![1, 1, 0, 1];
+        assert_eq!(repair_mkp(&mkp, &x), vec![1, 0, 0, 1]);
+    }
+
+    #[test]
+    fn test_repair_multiple_constraints() {
+        // 目的関数と制約が対応する場合 (Zitzler–Thiele)
+        // 利益重量比: [max(10/5, 4/1), max(2/1, 3/6), max(6/4, 12/2)]
+        //           = [4.0, 2.0, 6.0]
+        let mkp = MKP {
+            number_of_obj: 2,
+            number_of_items: 3,
+            capacity: vec![10, 8],
+            profit: vec![vec![10, 2, 6], vec![4, 3, 12]],
+            weight: vec![vec![5, 1, 4], vec![1, 6, 2]],
+        };
+        assert_eq!(removal_order(&mkp), vec![1, 0, 2]);
+        // 重さ [10, 9] は2つ目の制約に違反するため、アイテム1を取り除く
+        assert_eq!(repair_mkp(&mkp, &vec![1, 1, 1]), vec![1, 0, 1]);
+    }
+
+    #[test]
+    fn test_fit_mkp_with_repair_lamarckian() {
+        let mkp = create_mkp();
+        let x = vec![1, 1, 1, 1];
+        let (genome, objectives) =
+            fit_mkp_with_repair(&mkp, &x, RepairMode::Lamarckian);
+        assert_eq!(genome, vec![1, 0, 1, 0]);
+        assert_eq!(objectives, vec![16.0, 16.0]);
+    }
+
+    #[test]
+    fn test_fit_mkp_with_repair_baldwinian() {
+        let mkp = create_mkp();
+        let x = vec![1, 1, 1, 1];
+        let (genome, objectives) =
+            fit_mkp_with_repair(&mkp, &x, RepairMode::Baldwinian);
+        assert_eq!(genome, x);
+        assert_eq!(objectives, vec![16.0, 16.0]);
+    }
+}
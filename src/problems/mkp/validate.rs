@@ -0,0 +1,223 @@
+//! インスタンスの検証
+
+use std::fmt;
+
+use super::{MKP, MkpLoadError};
+
+/// 読み込みは可能だが、退化している可能性のあるデータについての警告
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MkpWarning {
+    /// 容量が0の制約がある
+    ZeroCapacity { constraint: usize },
+    /// 単独で容量を超えるアイテムがある
+    ItemExceedsCapacity { constraint: usize, item: usize },
+    /// すべての利益が0の目的関数がある
+    ZeroProfitRow { objective: usize },
+}
+
+impl fmt::Display for MkpWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ZeroCapacity { constraint } => {
+                write!(f, "capacity of constraint {} is zero", constraint)
+            }
+            Self::ItemExceedsCapacity { constraint, item } => write!(
+                f,
+                "item {} alone exceeds the capacity of constraint {}",
+                item, constraint
+            ),
+            Self::ZeroProfitRow { objective } => {
+                write!(
+                    f,
+                    "all profits of objective {} are zero",
+                    objective
+                )
+            }
+        }
+    }
+}
+
+/// 配列の長さを検査する
+fn check_len(
+    field: &'static str,
+    path: String,
+    expected: usize,
+    found: usize,
+) -> Result<(), MkpLoadError> {
+    if expected == found {
+        Ok(())
+    } else {
+        Err(MkpLoadError::DimensionMismatch {
+            field,
+            path,
+            expected,
+            found,
+        })
+    }
+}
+
+impl MKP {
+    /// インスタンスの次元の整合性を検査する
+    ///
+    /// 次元が一致しない場合はエラーを返す。
+    /// 次元が正しい場合は、退化したデータについての警告を返す。
+    pub fn validate(&self) -> Result<Vec<MkpWarning>, MkpLoadError> {
+        check_len(
+            "profit",
+            "$.profit".to_string(),
+            self.number_of_obj,
+            self.profit.len(),
+        )?;
+        self.profit.iter().enumerate().try_for_each(|(k, row)| {
+            check_len(
+                "profit",
+                format!("$.profit[{}]", k),
+                self.number_of_items,
+                row.len(),
+            )
+        })?;
+        check_len(
+            "weight",
+            "$.weight".to_string(),
+            self.capacity.len(),
+            self.weight.len(),
+        )?;
+        self.weight.iter().enumerate().try_for_each(|(j, row)| {
+            check_len(
+                "weight",
+                format!("$.weight[{}]", j),
+                self.number_of_items,
+                row.len(),
+            )
+        })?;
+
+        let zero_capacity = self
+            .capacity
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| **c == 0)
+            .map(|(constraint, _)| MkpWarning::ZeroCapacity {
+                constraint,
+            });
+        let heavy_items = self
+            .weight
+            .iter()
+            .zip(self.capacity.iter())
+            .enumerate()
+            .flat_map(|(constraint, (row, &c))| {
+                row.iter().enumerate().filter(move |(_, w)| **w > c).map(
+                    move |(item, _)| MkpWarning::ItemExceedsCapacity {
+                        constraint,
+                        item,
+                    },
+                )
+            });
+        let zero_profit = self
+            .profit
+            .iter()
+            .enumerate()
+            .filter(|(_, row)| row.iter().all(|p| *p == 0))
+            .map(|(objective, _)| MkpWarning::ZeroProfitRow { objective });
+
+        Ok(zero_capacity
+            .chain(heavy_items)
+            .chain(zero_profit)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_mkp() -> MKP {
+        MKP {
+            number_of_obj: 2,
+            number_of_items: 3,
+            capacity: vec![10],
+            profit: vec![vec![1, 2, 3], vec![4, 5, 6]],
+            weight: vec![vec![3, 4, 5]],
+        }
+    }
+
+    #[test]
+    fn test_validate_ok() {
+        assert_eq!(create_mkp().validate().unwrap(), vec![]);
+    }
+
+    #[test]
+    fn test_validate_profit_rows() {
+        // 利益の行数が目的関数の数と一致しない
+        let mkp = MKP {
+            profit: vec![vec![1, 2, 3]],
+            ..create_mkp()
+        };
+        match mkp.validate() {
+            Err(MkpLoadError::DimensionMismatch {
+                field,
+                path,
+                expected,
+                found,
+            }) => {
+                assert_eq!(field, "profit");
+                assert_eq!(path, "$.profit");
+                assert_eq!(expected, 2);
+                assert_eq!(found, 1);
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_validate_short_weight_row() {
+        // 重さの長さがアイテム数に足りない
+        let mkp = MKP {
+            weight: vec![vec![3, 4]],
+            ..create_mkp()
+        };
+        match mkp.validate() {
+            Err(MkpLoadError::DimensionMismatch { path, .. }) => {
+                assert_eq!(path, "$.weight[0]");
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_load_with_warnings() {
+        // 警告は表示せず、呼び出し側に返す
+        let content = r#"{
+            "number_of_obj": 1,
+            "number_of_items": 2,
+            "capacity": 0,
+            "profit": [[1, 2]],
+            "weight": [1, 1]
+        }"#;
+        let (mkp, warnings) =
+            MKP::from_json_str_with_warnings(content).unwrap();
+        assert_eq!(mkp.capacity, vec![0]);
+        assert!(
+            warnings.contains(&MkpWarning::ZeroCapacity { constraint: 0 })
+        );
+        assert_eq!(MKP::from_json_str(content).unwrap(), mkp);
+    }
+
+    #[test]
+    fn test_validate_warnings() {
+        let mkp = MKP {
+            capacity: vec![4],
+            profit: vec![vec![1, 2, 3], vec![0, 0, 0]],
+            ..create_mkp()
+        };
+        assert_eq!(
+            mkp.validate().unwrap(),
+            vec![
+                MkpWarning::ItemExceedsCapacity {
+                    constraint: 0,
+                    item: 2
+                },
+                MkpWarning::ZeroProfitRow { objective: 1 },
+            ]
+        );
+    }
+}
@@ -0,0 +1,307 @@
+//! インスタンスの書き出しと形式変換
+
+use serde_json::{Value, json};
+use std::{fs, path::Path};
+
+use super::{MKP, MkpLoadError};
+
+/// `to_json` のフィールドを書き出す順
+const JSON_FIELDS: [&str; 5] = [
+    "number_of_obj",
+    "number_of_items",
+    "capacity",
+    "profit",
+    "weight",
+];
+
+/// JSONの値を `problems/mkp/*.json` と同じレイアウトで整形する
+///
+/// 数値の配列は1行に並べ、2次元配列は1行に1行ずつ並べる。
+fn format_value(value: &Value) -> String {
+    let format_row = |row: &[Value]| {
+        let values: Vec<String> =
+            row.iter().map(Value::to_string).collect();
+        format!("[{}]", values.join(", "))
+    };
+    match value.as_array() {
+        Some(rows) if rows.first().is_some_and(Value::is_array) => {
+            let rows: Vec<String> = rows
+                .iter()
+                .filter_map(Value::as_array)
+                .map(|row| format!("        {}", format_row(row)))
+                .collect();
+            format!("[\n{}\n    ]", rows.join(",\n"))
+        }
+        Some(row) => format_row(row),
+        None => value.to_string(),
+    }
+}
+
+/// ファイルに書き出す
+fn write_file(path: &Path, content: &str) -> Result<(), MkpLoadError> {
+    fs::write(path, content).map_err(|e| MkpLoadError::Write {
+        path: path.to_path_buf(),
+        source: e,
+    })
+}
+
+impl MKP {
+    /// 単一容量の場合は容量と重さを数値と1次元配列で表す
+    fn is_single_capacity(&self) -> bool {
+        self.capacity.len() == 1 && self.weight.len() == 1
+    }
+
+    /// `problems/mkp/*.json` と同じスキーマのJSONに変換する
+    pub fn to_json(&self) -> Value {
+        let (capacity, weight) = if self.is_single_capacity() {
+            (json!(self.capacity[0]), json!(self.weight[0]))
+        } else {
+            (json!(self.capacity), json!(self.weight))
+        };
+
+        json!({
+            "number_of_obj": self.number_of_obj,
+            "number_of_items": self.number_of_items,
+            "capacity": capacity,
+            "profit": self.profit,
+            "weight": weight,
+        })
+    }
+
+    /// `problems/mkp/*.json` と同じレイアウトのJSON文字列に変換する
+    ///
+    /// 内容は `to_json` と同じで、利益と重さの各行は1行にまとめる。
+    pub fn to_json_string(&self) -> String {
+        let json = self.to_json();
+        let fields: Vec<String> = JSON_FIELDS
+            .iter()
+            .map(|&key| {
+                format!("    \"{}\": {}", key, format_value(&json[key]))
+            })
+            .collect();
+        format!("{{\n{}\n}}", fields.join(",\n"))
+    }
+
+    /// JSON形式でファイルに書き出す
+    pub fn write_to_file(&self, path: &Path) -> Result<(), MkpLoadError> {
+        write_file(path, &self.to_json_string())
+    }
+
+    /// Zitzler–Thiele 形式の文字列に変換する
+    ///
+    /// Zitzler–Thiele 形式ではナップザックと目的関数が1対1に対応する。
+    /// 単一容量の場合は、同じ容量と重さのナップザックを目的関数の数だけ並べる。
+    /// 制約の数が1でも目的関数の数でもない場合はエラーを返す。
+    pub fn to_zitzler_thiele_string(
+        &self,
+    ) -> Result<String, MkpLoadError> {
+        let constraint_of = |k: usize| {
+            if self.number_of_constraints() == 1 {
+                0
+            } else {
+                k
+            }
+        };
+        if self.number_of_constraints() != 1
+            && self.number_of_constraints() != self.number_of_obj
+        {
+            return Err(MkpLoadError::UnsupportedFormat {
+                format: "Zitzler-Thiele",
+                reason: format!(
+                    "{} constraints for {} objectives",
+                    self.number_of_constraints(),
+                    self.number_of_obj
+                ),
+            });
+        }
+
+        let header = format!(
+            "knapsack problem specification ({} knapsacks, {} items)",
+            self.number_of_obj, self.number_of_items
+        );
+        let knapsacks = (0..self.number_of_obj).map(|k| {
+            let j = constraint_of(k);
+            let items = (0..self.number_of_items).map(|i| {
+                format!(
+                    " item {}:\n  weight: +{}\n  profit: +{}",
+                    i + 1,
+                    self.weight[j][i],
+                    self.profit[k][i]
+                )
+            });
+            std::iter::once(format!(
+                "knapsack {}:\n capacity: +{}",
+                k + 1,
+                self.capacity[j]
+            ))
+            .chain(items)
+            .collect::<Vec<_>>()
+            .join("\n")
+        });
+
+        Ok(std::iter::once(header)
+            .chain(knapsacks)
+            .collect::<Vec<_>>()
+            .join("\n=\n")
+            + "\n")
+    }
+
+    /// Zitzler–Thiele 形式でファイルに書き出す
+    pub fn write_zitzler_thiele_file(
+        &self,
+        path: &Path,
+    ) -> Result<(), MkpLoadError> {
+        write_file(path, &self.to_zitzler_thiele_string()?)
+    }
+
+    /// すべての制約の容量と重さが同じ場合、1つの制約にまとめる
+    fn merge_identical_constraints(self) -> Self {
+        let identical = self.capacity.windows(2).all(|c| c[0] == c[1])
+            && self.weight.windows(2).all(|w| w[0] == w[1]);
+        if !identical || self.number_of_constraints() <= 1 {
+            return self;
+        }
+
+        Self {
+            capacity: self.capacity[..1].to_vec(),
+            weight: self.weight[..1].to_vec(),
+            ..self
+        }
+    }
+}
+
+/// JSON形式のファイルを Zitzler–Thiele 形式に変換する
+pub fn convert_json_to_zitzler_thiele(
+    src: &Path,
+    dst: &Path,
+) -> Result<(), MkpLoadError> {
+    MKP::from_file(src)?.write_zitzler_thiele_file(dst)
+}
+
+/// Zitzler–Thiele 形式のファイルをJSON形式に変換する
+///
+/// すべてのナップザックの容量と重さが同じ場合は、単一容量の形式で書き出す。
+pub fn convert_zitzler_thiele_to_json(
+    src: &Path,
+    dst: &Path,
+) -> Result<(), MkpLoadError> {
+    MKP::from_zitzler_thiele_file(src)?
+        .merge_identical_constraints()
+        .write_to_file(dst)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::super::MKPInstance;
+    use super::*;
+
+    /// テスト用の一時ファイルのパス
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "nsgaii-rust-{}-{}",
+            std::process::id(),
+            name
+        ))
+    }
+
+    fn create_multi_mkp() -> MKP {
+        MKP {
+            number_of_obj: 2,
+            number_of_items: 3,
+            capacity: vec![10, 8],
+            profit: vec![vec![1, 2, 3], vec![4, 5, 6]],
+            weight: vec![vec![3, 4, 5], vec![6, 1, 2]],
+        }
+    }
+
+    #[test]
+    fn test_to_json_string_matches_to_json() {
+        // 整形した文字列を読み込むと to_json と同じ値になる
+        MKPInstance::all()
+            .iter()
+            .map(|&instance| MKP::from_instance(instance).unwrap())
+            .chain(std::iter::once(create_multi_mkp()))
+            .for_each(|mkp| {
+                let parsed: Value =
+                    serde_json::from_str(&mkp.to_json_string()).unwrap();
+                assert_eq!(parsed, mkp.to_json());
+            });
+    }
+
+    #[test]
+    fn test_write_error() {
+        // 書き出しの失敗は読み込みの失敗と区別して報告する
+        let path = temp_path("missing-dir").join("instance.json");
+        match create_multi_mkp().write_to_file(&path) {
+            Err(e @ MkpLoadError::Write { .. }) => {
+                assert!(e.to_string().starts_with("failed to write"))
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        // load → save → load で同じインスタンスになる
+        let path = temp_path("round_trip.json");
+        MKPInstance::all().iter().for_each(|&instance| {
+            let mkp = MKP::from_instance(instance).unwrap();
+            mkp.write_to_file(&path).unwrap();
+            assert_eq!(MKP::from_file(&path).unwrap(), mkp);
+        });
+
+        let mkp = create_multi_mkp();
+        mkp.write_to_file(&path).unwrap();
+        assert_eq!(MKP::from_file(&path).unwrap(), mkp);
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_to_json_value_round_trip() {
+        let mkp = create_multi_mkp();
+        assert_eq!(MKP::from_json_value(&mkp.to_json()).unwrap().0, mkp);
+    }
+
+    #[test]
+    fn test_zitzler_thiele_round_trip() {
+        let mkp = create_multi_mkp();
+        let content = mkp.to_zitzler_thiele_string().unwrap();
+        assert_eq!(MKP::from_zitzler_thiele_str(&content).unwrap(), mkp);
+    }
+
+    #[test]
+    fn test_convert_json_to_zitzler_thiele_and_back() {
+        // 単一容量のJSON → Zitzler–Thiele → JSON で同じインスタンスになる
+        let zt_path = temp_path("convert.txt");
+        let json_path = temp_path("convert.json");
+        let instance = MKPInstance::P2N20Ins1;
+        fs::write(&json_path, instance.contents()).unwrap();
+
+        convert_json_to_zitzler_thiele(&json_path, &zt_path).unwrap();
+        let zt = MKP::from_zitzler_thiele_file(&zt_path).unwrap();
+        assert_eq!(zt.number_of_constraints(), 2);
+
+        convert_zitzler_thiele_to_json(&zt_path, &json_path).unwrap();
+        assert_eq!(
+            MKP::from_file(&json_path).unwrap(),
+            MKP::from_instance(instance).unwrap()
+        );
+        fs::remove_file(&zt_path).unwrap();
+        fs::remove_file(&json_path).unwrap();
+    }
+
+    #[test]
+    fn test_to_zitzler_thiele_string_dimension_mismatch() {
+        let mkp = MKP {
+            number_of_obj: 3,
+            profit: vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]],
+            ..create_multi_mkp()
+        };
+        assert!(matches!(
+            mkp.to_zitzler_thiele_string(),
+            Err(MkpLoadError::UnsupportedFormat { .. })
+        ));
+    }
+}
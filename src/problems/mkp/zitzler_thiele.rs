@@ -0,0 +1,293 @@
+//! Zitzler–Thiele 形式のインスタンスとパレートフロントの読み込み
+//!
+//! `knapsack.250.2` などのベンチマークインスタンスは次のテキスト形式で配布されている。
+//! ```text
+//! knapsack problem specification (2 knapsacks, 250 items)
+//! =
+//! knapsack 1:
+//!  capacity: +6853
+//!  item 1:
+//!   weight: +46
+//!   profit: +45
+//!  ...
+//! =
+//! knapsack 2:
+//!  ...
+//! ```
+//! ナップザックごとに容量と各アイテムの重さ・利益が与えられるため、
+//! ナップザックの数がそのまま目的関数の数と制約の数になる。
+
+use std::{fs, path::Path};
+
+use crate::individual::Objectives;
+
+use super::{MKP, MkpLoadError, MkpWarning};
+
+/// ファイルを文字列として読み込む
+fn read_file(path: &Path) -> Result<String, MkpLoadError> {
+    fs::read_to_string(path).map_err(|e| MkpLoadError::Io {
+        path: path.to_path_buf(),
+        source: e,
+    })
+}
+
+/// 空行を除いた各行を行番号（1始まり）とともに返す
+fn numbered_lines(content: &str) -> impl Iterator<Item = (usize, &str)> {
+    content
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty())
+}
+
+/// 解釈できない行のエラーを作る
+fn syntax_error(line: usize, content: &str) -> MkpLoadError {
+    MkpLoadError::Syntax {
+        line,
+        content: content.to_string(),
+    }
+}
+
+/// `key: +value` 形式の行から値を取り出す
+fn parse_field(line: &str, key: &str) -> Option<u64> {
+    line.strip_prefix(key)
+        .and_then(|rest| rest.strip_prefix(':'))
+        .map(|value| value.trim().trim_start_matches('+'))
+        .and_then(|value| value.parse().ok())
+}
+
+/// 見出し行からナップザックの数とアイテムの数を取り出す
+///
+/// `knapsack problem specification (2 knapsacks, 250 items)`
+fn parse_header(line: &str) -> Option<(usize, usize)> {
+    let inner = line.split_once('(')?.1.split_once(')')?.0;
+    let (knapsacks, items) = inner.split_once(',')?;
+    let count = |s: &str| s.split_whitespace().next()?.parse().ok();
+
+    count(knapsacks).zip(count(items))
+}
+
+/// 1つのナップザックの内容
+#[derive(Debug, Default)]
+struct Knapsack {
+    capacity: Option<usize>,
+    weight: Vec<usize>,
+    profit: Vec<u64>,
+}
+
+impl MKP {
+    /// Zitzler–Thiele 形式のファイルから問題を読み込む
+    ///
+    /// 退化したデータについての警告は破棄する。
+    pub fn from_zitzler_thiele_file(
+        path: &Path,
+    ) -> Result<Self, MkpLoadError> {
+        Self::from_zitzler_thiele_str(&read_file(path)?)
+    }
+
+    /// Zitzler–Thiele 形式の文字列から問題を読み込む
+    ///
+    /// 退化したデータについての警告は破棄する。
+    pub fn from_zitzler_thiele_str(
+        content: &str,
+    ) -> Result<Self, MkpLoadError> {
+        Self::from_zitzler_thiele_str_with_warnings(content)
+            .map(|(mkp, _)| mkp)
+    }
+
+    /// Zitzler–Thiele 形式の文字列から問題を読み込み、検証で得られた警告と
+    /// ともに返す
+    pub fn from_zitzler_thiele_str_with_warnings(
+        content: &str,
+    ) -> Result<(Self, Vec<MkpWarning>), MkpLoadError> {
+        let mut lines = numbered_lines(content);
+
+        let (number_of_knapsacks, number_of_items) = match lines.next() {
+            Some((line_no, line)) => parse_header(line)
+                .ok_or_else(|| syntax_error(line_no, line))?,
+            None => return Err(syntax_error(1, "")),
+        };
+
+        let mut knapsacks: Vec<Knapsack> = Vec::new();
+        for (line_no, line) in lines {
+            if line == "=" || line.starts_with("item") {
+                continue;
+            } else if line.starts_with("knapsack") {
+                knapsacks.push(Knapsack::default());
+            } else {
+                let knapsack = knapsacks
+                    .last_mut()
+                    .ok_or_else(|| syntax_error(line_no, line))?;
+                if let Some(c) = parse_field(line, "capacity") {
+                    knapsack.capacity = Some(c as usize);
+                } else if let Some(w) = parse_field(line, "weight") {
+                    knapsack.weight.push(w as usize);
+                } else if let Some(p) = parse_field(line, "profit") {
+                    knapsack.profit.push(p);
+                } else {
+                    return Err(syntax_error(line_no, line));
+                }
+            }
+        }
+
+        if knapsacks.len() != number_of_knapsacks {
+            return Err(MkpLoadError::DimensionMismatch {
+                field: "knapsack",
+                path: "specification".to_string(),
+                expected: number_of_knapsacks,
+                found: knapsacks.len(),
+            });
+        }
+
+        let capacity = knapsacks
+            .iter()
+            .enumerate()
+            .map(|(k, knapsack)| {
+                knapsack.capacity.ok_or_else(|| {
+                    MkpLoadError::MissingField {
+                        field: "capacity",
+                        path: format!("knapsack {}", k + 1),
+                    }
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for (k, knapsack) in knapsacks.iter().enumerate() {
+            for (field, found) in [
+                ("weight", knapsack.weight.len()),
+                ("profit", knapsack.profit.len()),
+            ] {
+                if found != number_of_items {
+                    return Err(MkpLoadError::DimensionMismatch {
+                        field,
+                        path: format!("knapsack {}", k + 1),
+                        expected: number_of_items,
+                        found,
+                    });
+                }
+            }
+        }
+
+        let (weight, profit) = knapsacks
+            .into_iter()
+            .map(|knapsack| (knapsack.weight, knapsack.profit))
+            .unzip();
+
+        Self {
+            number_of_obj: number_of_knapsacks,
+            number_of_items,
+            capacity,
+            profit,
+            weight,
+        }
+        .validate_on_load()
+    }
+}
+
+/// パレートフロントのファイルを読み込む
+///
+/// 1行に1つの解の目的関数値を空白区切りで並べた形式とする。
+pub fn read_pareto_front(
+    path: &Path,
+) -> Result<Vec<Objectives>, MkpLoadError> {
+    parse_pareto_front(&read_file(path)?)
+}
+
+/// パレートフロントの文字列を読み込む
+pub fn parse_pareto_front(
+    content: &str,
+) -> Result<Vec<Objectives>, MkpLoadError> {
+    numbered_lines(content)
+        .map(|(line_no, line)| {
+            line.split_whitespace()
+                .map(|value| value.trim_start_matches('+').parse::<f64>())
+                .collect::<Result<Objectives, _>>()
+                .map_err(|_| syntax_error(line_no, line))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INSTANCE: &str =
+        "knapsack problem specification (2 knapsacks, 3 items)
+=
+knapsack 1:
+ capacity: +10
+ item 1:
+  weight: +3
+  profit: +1
+ item 2:
+  weight: +4
+  profit: +2
+ item 3:
+  weight: +5
+  profit: +3
+=
+knapsack 2:
+ capacity: +8
+ item 1:
+  weight: +6
+  profit: +4
+ item 2:
+  weight: +1
+  profit: +5
+ item 3:
+  weight: +2
+  profit: +6
+";
+
+    #[test]
+    fn test_parse_header() {
+        let header =
+            "knapsack problem specification (2 knapsacks, 250 items)";
+        assert_eq!(parse_header(header), Some((2, 250)));
+    }
+
+    #[test]
+    fn test_from_zitzler_thiele_str() {
+        let mkp = MKP::from_zitzler_thiele_str(INSTANCE).unwrap();
+        assert_eq!(mkp.number_of_obj, 2);
+        assert_eq!(mkp.number_of_items, 3);
+        assert_eq!(mkp.capacity, vec![10, 8]);
+        assert_eq!(mkp.profit, vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(mkp.weight, vec![vec![3, 4, 5], vec![6, 1, 2]]);
+    }
+
+    #[test]
+    fn test_from_zitzler_thiele_str_missing_item() {
+        // アイテム数が見出しと一致しない場合はエラー
+        let content = INSTANCE
+            .replace("(2 knapsacks, 3 items)", "(2 knapsacks, 4 items)");
+        match MKP::from_zitzler_thiele_str(&content) {
+            Err(MkpLoadError::DimensionMismatch {
+                field,
+                path,
+                expected,
+                found,
+            }) => {
+                assert_eq!(field, "weight");
+                assert_eq!(path, "knapsack 1");
+                assert_eq!(expected, 4);
+                assert_eq!(found, 3);
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_pareto_front() {
+        let front =
+            parse_pareto_front("4052 3986\n\n4033 4002\n").unwrap();
+        assert_eq!(
+            front,
+            vec![vec![4052.0, 3986.0], vec![4033.0, 4002.0]]
+        );
+        match parse_pareto_front("4052 3986\n4052 abc\n") {
+            Err(MkpLoadError::Syntax { line, .. }) => assert_eq!(line, 2),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+}
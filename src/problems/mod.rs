@@ -1,3 +1,29 @@
+mod closure;
+mod constrained;
+mod dtlz;
+mod facility;
+mod flowshop;
 mod mkp;
+mod parse;
+mod portfolio;
+mod problem;
+mod pseudo_boolean;
+mod setcover;
+mod tsp;
+mod wfg;
+mod zdt;
 
-use mkp::{MKP, MKPInstance, fit_mkp};
+pub use closure::*;
+pub use constrained::*;
+pub use dtlz::*;
+pub use facility::*;
+pub use flowshop::*;
+pub use mkp::*;
+pub use parse::*;
+pub use portfolio::*;
+pub use problem::*;
+pub use pseudo_boolean::*;
+pub use setcover::*;
+pub use tsp::*;
+pub use wfg::*;
+pub use zdt::*;
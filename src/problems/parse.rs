@@ -0,0 +1,160 @@
+//! テキスト形式のインスタンスファイルの読み込みに共通するエラーと補助関数
+//!
+//! TSPLIB, Taillard, OR-Library などの形式の読み込みで共有する。
+
+use std::{fmt, fs, io, path::Path, path::PathBuf, str::FromStr};
+
+/// インスタンスの読み込みで発生するエラー
+#[derive(Debug)]
+pub enum LoadError {
+    /// ファイルを読み込めない
+    Io { path: PathBuf, source: io::Error },
+
+    /// インスタンスが1つも与えられていない
+    NoInstances,
+
+    /// 必須のフィールドやセクションがない
+    MissingField { field: &'static str },
+
+    /// 対応していない値（`EDGE_WEIGHT_TYPE` など）
+    Unsupported { field: &'static str, value: String },
+
+    /// 値の数が一致しない
+    DimensionMismatch {
+        field: &'static str,
+        expected: usize,
+        found: usize,
+    },
+
+    /// 添字が範囲外
+    IndexOutOfRange { line: usize, index: usize },
+
+    /// ファイルが途中で終わっている
+    UnexpectedEof { field: &'static str },
+
+    /// 行や値を解釈できない
+    Syntax { line: usize, content: String },
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io { path, source } => {
+                write!(f, "failed to read {}: {}", path.display(), source)
+            }
+            Self::NoInstances => write!(f, "no instances given"),
+            Self::MissingField { field } => {
+                write!(f, "missing field `{}`", field)
+            }
+            Self::Unsupported { field, value } => {
+                write!(f, "unsupported {}: {}", field, value)
+            }
+            Self::DimensionMismatch {
+                field,
+                expected,
+                found,
+            } => write!(
+                f,
+                "dimension mismatch for `{}`: expected {}, found {}",
+                field, expected, found
+            ),
+            Self::IndexOutOfRange { line, index } => {
+                write!(f, "index {} out of range at line {}", index, line)
+            }
+            Self::UnexpectedEof { field } => {
+                write!(
+                    f,
+                    "unexpected end of file while reading `{}`",
+                    field
+                )
+            }
+            Self::Syntax { line, content } => {
+                write!(f, "syntax error at line {}: {}", line, content)
+            }
+        }
+    }
+}
+
+impl std::error::Error for LoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// ファイルを文字列として読み込む
+pub(super) fn read_file(path: &Path) -> Result<String, LoadError> {
+    fs::read_to_string(path).map_err(|e| LoadError::Io {
+        path: path.to_path_buf(),
+        source: e,
+    })
+}
+
+/// 解釈できない行や値のエラーを作る
+pub(super) fn syntax_error(line: usize, content: &str) -> LoadError {
+    LoadError::Syntax {
+        line,
+        content: content.to_string(),
+    }
+}
+
+/// 前後の空白を除いた空でない行と、その行番号（1始まり）
+pub(super) fn lines(content: &str) -> impl Iterator<Item = (usize, &str)> {
+    content
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty())
+}
+
+/// 空白で区切られた値と、その行番号（1始まり）
+pub(super) fn tokens(
+    content: &str,
+) -> impl Iterator<Item = (usize, &str)> {
+    content.lines().enumerate().flat_map(|(i, line)| {
+        line.split_whitespace().map(move |t| (i + 1, t))
+    })
+}
+
+/// 値を1つ読む
+///
+/// 値がなければ `field` を読み込み中の [`LoadError::UnexpectedEof`] を返す。
+pub(super) fn next_token<'a>(
+    tokens: &mut impl Iterator<Item = (usize, &'a str)>,
+    field: &'static str,
+) -> Result<(usize, &'a str), LoadError> {
+    tokens.next().ok_or(LoadError::UnexpectedEof { field })
+}
+
+/// 値を1つ読み、`T` として解釈する
+pub(super) fn next_value<'a, T: FromStr>(
+    tokens: &mut impl Iterator<Item = (usize, &'a str)>,
+    field: &'static str,
+) -> Result<T, LoadError> {
+    let (line, token) = next_token(tokens, field)?;
+    token.parse().map_err(|_| syntax_error(line, token))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokens() {
+        let mut t = tokens(" 3 4\n\n 1.5 x\n");
+        assert_eq!(next_value::<usize>(&mut t, "a").unwrap(), 3);
+        assert_eq!(next_token(&mut t, "b").unwrap(), (1, "4"));
+        assert_eq!(next_value::<f64>(&mut t, "c").unwrap(), 1.5);
+        // 解釈できない値は行番号とともに報告する
+        assert!(matches!(
+            next_value::<f64>(&mut t, "d"),
+            Err(LoadError::Syntax { line: 3, .. })
+        ));
+        assert!(matches!(
+            next_value::<f64>(&mut t, "e"),
+            Err(LoadError::UnexpectedEof { field: "e" })
+        ));
+    }
+}
@@ -0,0 +1,250 @@
+//! 平均分散モデルのポートフォリオ選択問題
+//!
+//! リターンの期待値を最大化し、分散を最小化する。組み入れる資産の数と
+//! 各資産の比率に上下限を設け、違反量を制約として報告する。
+
+mod orlib;
+
+use crate::individual::{Individual, Objectives};
+
+use super::{Problem, Sense};
+
+/// 資産の選択（ビット列）と比率（非負の実数）を組み合わせた解
+///
+/// 選択された資産の比率を合計1に正規化したものを実際の比率とする。
+#[derive(Debug, Clone, PartialEq)]
+pub struct PortfolioGenome {
+    pub selection: Individual,
+    pub weights: Vec<f64>,
+}
+
+/// 資産の数と比率の上下限
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PortfolioLimits {
+    /// 組み入れる資産の数の下限
+    pub min_assets: usize,
+    /// 組み入れる資産の数の上限
+    pub max_assets: usize,
+    /// 組み入れた資産の比率の下限
+    pub min_weight: f64,
+    /// 組み入れた資産の比率の上限
+    pub max_weight: f64,
+}
+
+/// ポートフォリオ選択問題
+#[derive(Debug, Clone, PartialEq)]
+pub struct Portfolio {
+    /// 各資産の平均リターン
+    pub mean: Vec<f64>,
+    /// リターンの共分散行列
+    pub covariance: Vec<Vec<f64>>,
+    pub limits: PortfolioLimits,
+}
+
+impl Portfolio {
+    /// 平均リターンと共分散行列から問題を作る
+    ///
+    /// 資産の数と比率は制限しない（1資産以上、比率は `[0, 1]`）。
+    pub fn new(mean: Vec<f64>, covariance: Vec<Vec<f64>>) -> Self {
+        let n = mean.len();
+        assert!(
+            covariance.len() == n
+                && covariance.iter().all(|r| r.len() == n),
+            "covariance must be an n x n matrix"
+        );
+        Self {
+            mean,
+            covariance,
+            limits: PortfolioLimits {
+                min_assets: 1,
+                max_assets: n,
+                min_weight: 0.0,
+                max_weight: 1.0,
+            },
+        }
+    }
+
+    /// 資産の数と比率の上下限を設定する
+    pub fn with_limits(self, limits: PortfolioLimits) -> Self {
+        assert!(
+            limits.min_assets <= limits.max_assets,
+            "min_assets must not exceed max_assets"
+        );
+        assert!(
+            0.0 <= limits.min_weight
+                && limits.min_weight <= limits.max_weight
+                && limits.max_weight <= 1.0,
+            "weights must satisfy 0 <= min_weight <= max_weight <= 1"
+        );
+        Self { limits, ..self }
+    }
+
+    /// 資産の数
+    pub fn number_of_assets(&self) -> usize {
+        self.mean.len()
+    }
+
+    /// 各資産の実際の比率
+    ///
+    /// 選択された資産の比率を合計1に正規化する。選択された資産の比率が
+    /// すべて0の場合は均等に配分する。選択されていない資産の比率は0。
+    pub fn decode(&self, genome: &PortfolioGenome) -> Vec<f64> {
+        let raw: Vec<f64> = genome
+            .selection
+            .iter()
+            .zip(&genome.weights)
+            .map(|(&s, &w)| if s == 1 { w.max(0.0) } else { 0.0 })
+            .collect();
+        let total: f64 = raw.iter().sum();
+        if total > 0.0 {
+            return raw.iter().map(|w| w / total).collect();
+        }
+
+        let selected =
+            genome.selection.iter().filter(|&&s| s == 1).count();
+        genome
+            .selection
+            .iter()
+            .map(|&s| if s == 1 { 1.0 / selected as f64 } else { 0.0 })
+            .collect()
+    }
+
+    /// 比率に対するリターンの期待値
+    pub fn expected_return(&self, weights: &[f64]) -> f64 {
+        weights.iter().zip(&self.mean).map(|(w, m)| w * m).sum()
+    }
+
+    /// 比率に対するリターンの分散
+    pub fn variance(&self, weights: &[f64]) -> f64 {
+        weights
+            .iter()
+            .zip(&self.covariance)
+            .map(|(wi, row)| {
+                wi * weights
+                    .iter()
+                    .zip(row)
+                    .map(|(wj, c)| wj * c)
+                    .sum::<f64>()
+            })
+            .sum()
+    }
+}
+
+impl Problem for Portfolio {
+    type Solution = PortfolioGenome;
+
+    fn number_of_objectives(&self) -> usize {
+        2
+    }
+
+    fn senses(&self) -> Vec<Sense> {
+        vec![Sense::Maximize, Sense::Minimize]
+    }
+
+    fn evaluate(&self, x: &Self::Solution) -> Objectives {
+        let weights = self.decode(x);
+        vec![self.expected_return(&weights), self.variance(&weights)]
+    }
+
+    fn number_of_constraints(&self) -> usize {
+        3
+    }
+
+    /// 資産の数の下限・上限と、比率の上下限の違反量
+    fn constraint_violation(&self, x: &Self::Solution) -> Vec<f64> {
+        let limits = &self.limits;
+        let selected = x.selection.iter().filter(|&&s| s == 1).count();
+        let weight_violation: f64 = self
+            .decode(x)
+            .iter()
+            .zip(&x.selection)
+            .filter(|(_, s)| **s == 1)
+            .map(|(&w, _)| {
+                (limits.min_weight - w).max(0.0)
+                    + (w - limits.max_weight).max(0.0)
+            })
+            .sum();
+        vec![
+            limits.min_assets.saturating_sub(selected) as f64,
+            selected.saturating_sub(limits.max_assets) as f64,
+            weight_violation,
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_portfolio() -> Portfolio {
+        Portfolio::new(
+            vec![0.01, 0.02, 0.03],
+            vec![
+                vec![0.01, 0.0, 0.0],
+                vec![0.0, 0.04, 0.0],
+                vec![0.0, 0.0, 0.09],
+            ],
+        )
+    }
+
+    #[test]
+    fn test_decode() {
+        let portfolio = create_portfolio();
+        let genome = PortfolioGenome {
+            selection: vec![1, 0, 1],
+            weights: vec![0.2, 0.9, 0.6],
+        };
+        let weights = portfolio.decode(&genome);
+        assert!((weights[0] - 0.25).abs() < 1e-12);
+        assert_eq!(weights[1], 0.0);
+        assert!((weights[2] - 0.75).abs() < 1e-12);
+
+        // 比率がすべて0なら均等に配分する
+        let zero = PortfolioGenome {
+            selection: vec![1, 1, 0],
+            weights: vec![0.0; 3],
+        };
+        assert_eq!(portfolio.decode(&zero), vec![0.5, 0.5, 0.0]);
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let portfolio = create_portfolio();
+        let genome = PortfolioGenome {
+            selection: vec![1, 1, 0],
+            weights: vec![1.0, 1.0, 0.0],
+        };
+        let f = portfolio.evaluate(&genome);
+        assert!((f[0] - 0.015).abs() < 1e-12);
+        // 0.25 × 0.01 + 0.25 × 0.04
+        assert!((f[1] - 0.0125).abs() < 1e-12);
+        assert_eq!(portfolio.evaluate_minimised(&genome)[0], -f[0]);
+    }
+
+    #[test]
+    fn test_constraint_violation() {
+        let portfolio = create_portfolio().with_limits(PortfolioLimits {
+            min_assets: 1,
+            max_assets: 2,
+            min_weight: 0.1,
+            max_weight: 0.6,
+        });
+        let genome = PortfolioGenome {
+            selection: vec![1, 1, 1],
+            weights: vec![0.05, 0.25, 0.7],
+        };
+        let violation = portfolio.constraint_violation(&genome);
+        assert_eq!(violation[..2], [0.0, 1.0]);
+        // 0.1 - 0.05 と 0.7 - 0.6
+        assert!((violation[2] - 0.15).abs() < 1e-12);
+
+        let empty = PortfolioGenome {
+            selection: vec![0; 3],
+            weights: vec![0.5; 3],
+        };
+        assert_eq!(
+            portfolio.constraint_violation(&empty),
+            vec![1.0, 0.0, 0.0]
+        );
+    }
+}
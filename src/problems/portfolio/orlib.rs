@@ -0,0 +1,151 @@
+//! OR-Library 形式（port1–port5）のインスタンスの読み込み
+//!
+//! ```text
+//! 31
+//! 0.001309 0.043208
+//! ...
+//! 1 1 1.000000
+//! 1 2 0.562289
+//! ...
+//! ```
+//! 1行目に資産の数 `N`、続く `N` 行に各資産の平均リターンと標準偏差、
+//! 残りの行に `i j 相関係数`（1始まり、`i <= j`）を並べる。
+//! 値の区切りには空白のほかカンマも使える。
+
+use std::path::Path;
+
+use crate::problems::parse::{LoadError, lines, read_file, syntax_error};
+
+use super::Portfolio;
+
+/// 空白またはカンマで区切られた値
+fn fields(line: &str) -> impl Iterator<Item = &str> {
+    line.split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|v| !v.is_empty())
+}
+
+impl Portfolio {
+    /// OR-Library 形式のファイルから問題を読み込む
+    pub fn from_orlib_file(path: &Path) -> Result<Self, LoadError> {
+        Self::from_orlib_str(&read_file(path)?)
+    }
+
+    /// OR-Library 形式の文字列から問題を読み込む
+    ///
+    /// 与えられていない相関係数は0（対角成分は1）とみなす。
+    pub fn from_orlib_str(content: &str) -> Result<Self, LoadError> {
+        let mut lines = lines(content);
+
+        let n = match lines.next() {
+            Some((line_no, line)) => line
+                .parse::<usize>()
+                .map_err(|_| syntax_error(line_no, line))?,
+            None => {
+                return Err(LoadError::MissingField {
+                    field: "number of assets",
+                });
+            }
+        };
+
+        let mut mean = Vec::with_capacity(n);
+        let mut sd = Vec::with_capacity(n);
+        for (line_no, line) in lines.by_ref().take(n) {
+            match fields(line)
+                .map(|v| v.parse::<f64>())
+                .collect::<Vec<_>>()[..]
+            {
+                [Ok(m), Ok(s)] => {
+                    mean.push(m);
+                    sd.push(s);
+                }
+                _ => return Err(syntax_error(line_no, line)),
+            }
+        }
+        if mean.len() != n {
+            return Err(LoadError::DimensionMismatch {
+                field: "mean return",
+                expected: n,
+                found: mean.len(),
+            });
+        }
+
+        let mut correlation = vec![vec![0.0; n]; n];
+        (0..n).for_each(|i| correlation[i][i] = 1.0);
+        for (line_no, line) in lines {
+            let values: Vec<&str> = fields(line).collect();
+            let (i, j, c) = match values[..] {
+                [i, j, c] => (
+                    i.parse::<usize>()
+                        .map_err(|_| syntax_error(line_no, line))?,
+                    j.parse::<usize>()
+                        .map_err(|_| syntax_error(line_no, line))?,
+                    c.parse::<f64>()
+                        .map_err(|_| syntax_error(line_no, line))?,
+                ),
+                _ => return Err(syntax_error(line_no, line)),
+            };
+            if let Some(&index) = [i, j].iter().find(|&&k| k == 0 || k > n)
+            {
+                return Err(LoadError::IndexOutOfRange {
+                    line: line_no,
+                    index,
+                });
+            }
+            correlation[i - 1][j - 1] = c;
+            correlation[j - 1][i - 1] = c;
+        }
+
+        let covariance = (0..n)
+            .map(|i| {
+                (0..n).map(|j| correlation[i][j] * sd[i] * sd[j]).collect()
+            })
+            .collect();
+        Ok(Self::new(mean, covariance))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTENT: &str = "3
+0.01 0.1
+0.02, 0.2
+0.03 0.3
+1 1 1.0
+1 2 0.5
+1 3 0.0
+2 2 1.0
+2 3 -0.5
+3 3 1.0
+";
+
+    #[test]
+    fn test_from_orlib_str() {
+        let portfolio = Portfolio::from_orlib_str(CONTENT).unwrap();
+        assert_eq!(portfolio.number_of_assets(), 3);
+        assert_eq!(portfolio.mean, vec![0.01, 0.02, 0.03]);
+        let cov = &portfolio.covariance;
+        assert!((cov[0][0] - 0.01).abs() < 1e-12);
+        // 0.5 × 0.1 × 0.2
+        assert!((cov[0][1] - 0.01).abs() < 1e-12);
+        assert!((cov[2][1] + 0.03).abs() < 1e-12);
+        assert_eq!(cov[0][2], 0.0);
+    }
+
+    #[test]
+    fn test_from_orlib_str_errors() {
+        let out_of_range = CONTENT.replace("3 3 1.0", "3 4 1.0");
+        assert!(matches!(
+            Portfolio::from_orlib_str(&out_of_range),
+            Err(LoadError::IndexOutOfRange { line: 10, index: 4 })
+        ));
+        let broken = CONTENT.replace("0.03 0.3", "0.03");
+        match Portfolio::from_orlib_str(&broken) {
+            Err(LoadError::Syntax { line, .. }) => {
+                assert_eq!(line, 4)
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+}
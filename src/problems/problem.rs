@@ -0,0 +1,222 @@
+//! 最適化問題の共通インターフェース
+
+use crate::individual::Objectives;
+
+/// 目的関数の最適化の向き
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Sense {
+    /// 最小化
+    Minimize,
+    /// 最大化
+    Maximize,
+}
+
+/// 多目的最適化問題
+pub trait Problem {
+    /// 解の表現
+    type Solution;
+
+    /// 目的関数の数
+    fn number_of_objectives(&self) -> usize;
+
+    /// 各目的関数の最適化の向き
+    ///
+    /// 既定ではすべて最小化とする。
+    fn senses(&self) -> Vec<Sense> {
+        vec![Sense::Minimize; self.number_of_objectives()]
+    }
+
+    /// 目的関数値を計算する
+    ///
+    /// 制約違反は含めず、[`Problem::constraint_violation`] で別に求める。
+    fn evaluate(&self, x: &Self::Solution) -> Objectives;
+
+    /// 制約の数
+    fn number_of_constraints(&self) -> usize {
+        0
+    }
+
+    /// 各制約の違反量
+    ///
+    /// 制約を満たしている場合は0、違反している場合は正の値とする。
+    fn constraint_violation(&self, _x: &Self::Solution) -> Vec<f64> {
+        Vec::new()
+    }
+
+    /// すべての目的関数を最小化に揃えた目的関数値
+    ///
+    /// 最大化の目的関数は符号を反転する。
+    fn evaluate_minimised(&self, x: &Self::Solution) -> Objectives {
+        self.evaluate(x)
+            .into_iter()
+            .zip(self.senses())
+            .map(|(f, sense)| match sense {
+                Sense::Minimize => f,
+                Sense::Maximize => -f,
+            })
+            .collect()
+    }
+}
+
+/// 実数ベクトルを解とする問題の変数の範囲
+pub trait VariableBounds {
+    /// 各変数の `(下限, 上限)`
+    fn bounds(&self) -> Vec<(f64, f64)>;
+
+    /// 変数の数
+    fn number_of_variables(&self) -> usize {
+        self.bounds().len()
+    }
+}
+
+/// 既知のパレートフロントを持つ問題
+pub trait ParetoFront {
+    /// パレートフロント上の点を生成する
+    ///
+    /// `resolution` は生成する点の数の目安で、正確な数は問題によって異なる。
+    fn pareto_front(&self, resolution: usize) -> Vec<Objectives>;
+}
+
+/// 最小化において `a` が `b` を支配する場合true
+fn dominates_min(a: &Objectives, b: &Objectives) -> bool {
+    a.iter().zip(b.iter()).all(|(x, y)| x <= y)
+        && a.iter().zip(b.iter()).any(|(x, y)| x < y)
+}
+
+/// 最小化において非支配な点だけを残す
+pub fn nondominated_points(points: Vec<Objectives>) -> Vec<Objectives> {
+    points
+        .iter()
+        .filter(|p| !points.iter().any(|q| dominates_min(q, p)))
+        .cloned()
+        .collect()
+}
+
+/// `sequence` が `0..n` の順列からどれだけ外れているか
+///
+/// 現れない値の数と、範囲外または重複した要素の数の和を返す。
+/// 順列であれば0となる。
+pub fn permutation_violation(sequence: &[usize], n: usize) -> usize {
+    let mut seen = vec![false; n];
+    let extra = sequence
+        .iter()
+        .filter(|&&v| v >= n || std::mem::replace(&mut seen[v], true))
+        .count();
+    let missing = seen.iter().filter(|&&s| !s).count();
+    missing + extra
+}
+
+/// Das–Dennis の単体格子点
+///
+/// 各成分が `1/divisions` の倍数で合計が1となる `number_of_obj` 次元の点を
+/// すべて列挙する。点の数は `C(divisions + number_of_obj - 1, number_of_obj - 1)`。
+/// `number_of_obj` が0の場合は空の集合を返す。
+pub fn das_dennis_points(
+    number_of_obj: usize,
+    divisions: usize,
+) -> Vec<Vec<f64>> {
+    /// 残り `remaining` を `parts` 個の非負整数に分ける組み合わせ
+    fn compositions(remaining: usize, parts: usize) -> Vec<Vec<usize>> {
+        if parts == 1 {
+            return vec![vec![remaining]];
+        }
+        (0..=remaining)
+            .rev()
+            .flat_map(|first| {
+                compositions(remaining - first, parts - 1).into_iter().map(
+                    move |mut rest| {
+                        rest.insert(0, first);
+                        rest
+                    },
+                )
+            })
+            .collect()
+    }
+
+    if number_of_obj == 0 {
+        return Vec::new();
+    }
+
+    compositions(divisions, number_of_obj)
+        .into_iter()
+        .map(|c| c.iter().map(|&v| v as f64 / divisions as f64).collect())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Negate;
+
+    impl Problem for Negate {
+        type Solution = Vec<f64>;
+
+        fn number_of_objectives(&self) -> usize {
+            2
+        }
+
+        fn senses(&self) -> Vec<Sense> {
+            vec![Sense::Minimize, Sense::Maximize]
+        }
+
+        fn evaluate(&self, x: &Self::Solution) -> Objectives {
+            x.clone()
+        }
+    }
+
+    #[test]
+    fn test_evaluate_minimised() {
+        // 最大化の目的関数だけ符号が反転する
+        let problem = Negate;
+        assert_eq!(
+            problem.evaluate_minimised(&vec![1.0, 2.0]),
+            vec![1.0, -2.0]
+        );
+        assert_eq!(problem.number_of_constraints(), 0);
+        assert!(problem.constraint_violation(&vec![1.0, 2.0]).is_empty());
+    }
+
+    #[test]
+    fn test_nondominated_points() {
+        let points = vec![
+            vec![1.0, 3.0],
+            vec![2.0, 2.0],
+            vec![2.0, 3.0],
+            vec![3.0, 1.0],
+        ];
+        assert_eq!(
+            nondominated_points(points),
+            vec![vec![1.0, 3.0], vec![2.0, 2.0], vec![3.0, 1.0]]
+        );
+    }
+
+    #[test]
+    fn test_das_dennis_points() {
+        let points = das_dennis_points(3, 2);
+        assert_eq!(points.len(), 6);
+        assert_eq!(points[0], vec![1.0, 0.0, 0.0]);
+        points.iter().for_each(|p| {
+            assert!((p.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+        });
+        assert_eq!(das_dennis_points(5, 6).len(), 210);
+    }
+
+    #[test]
+    fn test_permutation_violation() {
+        assert_eq!(permutation_violation(&[2, 0, 3, 1], 4), 0);
+        // 2が現れず、1が重複している
+        assert_eq!(permutation_violation(&[0, 1, 1, 3], 4), 2);
+        // 3が現れない
+        assert_eq!(permutation_violation(&[0, 1, 2], 4), 1);
+        // 3が現れず、4が範囲外
+        assert_eq!(permutation_violation(&[0, 1, 2, 4], 4), 2);
+    }
+
+    #[test]
+    fn test_das_dennis_points_no_objectives() {
+        // 目的関数が0個の場合は点がない
+        assert!(das_dennis_points(0, 4).is_empty());
+        assert_eq!(das_dennis_points(1, 4), vec![vec![1.0]]);
+    }
+}
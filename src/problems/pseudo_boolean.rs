@@ -0,0 +1,279 @@
+//! 実行時間解析で用いられる擬似ブール関数の多目的問題
+//!
+//! OneMinMax, LeadingOnesTrailingZeroes (LOTZ), COCZ と、そのm目的版
+//! （mOMM, mLOTZ, mCOCZ）。すべて最大化。
+//!
+//! mOMM と mLOTZ はビット列を `m/2` 個のブロックに分け、それぞれに2目的問題を
+//! 割り当てる。mCOCZ は Laumanns, Thiele, Zitzler (2004) に従い、前半を
+//! すべての目的関数に共通の協調部分とし、後半だけを `m/2` 個の競合する
+//! ブロックに分ける。
+
+use crate::individual::{Individual, Objectives};
+
+use super::{ParetoFront, Problem, Sense};
+
+/// 擬似ブール関数の問題の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PseudoBooleanKind {
+    /// 1の数と0の数
+    OneMinMax,
+    /// 先頭から連続する1の数と末尾から連続する0の数
+    Lotz,
+    /// 1の数と、前半の1の数と後半の0の数の和
+    ///
+    /// m目的では前半の1の数に、後半の各ブロックの1の数または0の数を加える。
+    Cocz,
+}
+
+/// 擬似ブール関数の多目的問題
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PseudoBoolean {
+    pub kind: PseudoBooleanKind,
+    pub number_of_bits: usize,
+    pub number_of_obj: usize,
+}
+
+impl PseudoBoolean {
+    /// 2目的の問題を作る
+    pub fn new(kind: PseudoBooleanKind, number_of_bits: usize) -> Self {
+        Self::with_objectives(kind, number_of_bits, 2)
+    }
+
+    /// m目的の問題を作る
+    ///
+    /// `m` は偶数で、ビット数は `m/2` の倍数でなければならない。
+    /// COCZ ではビット数が `m` の倍数でなければならない。
+    pub fn with_objectives(
+        kind: PseudoBooleanKind,
+        number_of_bits: usize,
+        number_of_obj: usize,
+    ) -> Self {
+        assert!(
+            number_of_obj >= 2 && number_of_obj % 2 == 0,
+            "number of objectives must be even"
+        );
+        assert!(
+            number_of_bits > 0
+                && number_of_bits % (number_of_obj / 2) == 0,
+            "number of bits must be a multiple of m/2"
+        );
+        let problem = Self {
+            kind,
+            number_of_bits,
+            number_of_obj,
+        };
+        if kind == PseudoBooleanKind::Cocz {
+            assert!(
+                number_of_bits % number_of_obj == 0,
+                "COCZ needs the number of bits to be a multiple of m"
+            );
+        }
+        problem
+    }
+
+    /// 1ブロックのビット数
+    ///
+    /// COCZ では後半の競合部分を分けたブロックのビット数（`n/m`）。
+    pub fn block_size(&self) -> usize {
+        match self.kind {
+            PseudoBooleanKind::Cocz => {
+                self.number_of_bits / self.number_of_obj
+            }
+            _ => 2 * self.number_of_bits / self.number_of_obj,
+        }
+    }
+
+    /// パレートフロントの点の数
+    ///
+    /// OneMinMax と LOTZ は `(2n/m + 1)^(m/2)`、COCZ は `(n/m + 1)^(m/2)`。
+    pub fn pareto_front_size(&self) -> usize {
+        (self.block_size() + 1).pow((self.number_of_obj / 2) as u32)
+    }
+
+    /// 1ブロックの2目的の値
+    ///
+    /// COCZ では協調部分の1の数 `shared` を両方に加える。
+    fn block_objectives(&self, bits: &[u8], shared: usize) -> [usize; 2] {
+        let ones = unitation(bits);
+        match self.kind {
+            PseudoBooleanKind::OneMinMax => [ones, bits.len() - ones],
+            PseudoBooleanKind::Lotz => [
+                bits.iter().take_while(|&&b| b == 1).count(),
+                bits.iter().rev().take_while(|&&b| b == 0).count(),
+            ],
+            PseudoBooleanKind::Cocz => {
+                [shared + ones, shared + bits.len() - ones]
+            }
+        }
+    }
+
+    /// 1ブロックのパレートフロント
+    ///
+    /// COCZ では協調部分がすべて1の解がパレート最適となる。
+    fn block_front(&self) -> Vec<[usize; 2]> {
+        let b = self.block_size();
+        let shared = match self.kind {
+            PseudoBooleanKind::Cocz => self.number_of_bits / 2,
+            _ => 0,
+        };
+        (0..=b).map(|i| [shared + i, shared + b - i]).collect()
+    }
+}
+
+/// 1の数
+fn unitation(bits: &[u8]) -> usize {
+    bits.iter().filter(|&&b| b == 1).count()
+}
+
+impl Problem for PseudoBoolean {
+    type Solution = Individual;
+
+    fn number_of_objectives(&self) -> usize {
+        self.number_of_obj
+    }
+
+    fn senses(&self) -> Vec<Sense> {
+        vec![Sense::Maximize; self.number_of_obj]
+    }
+
+    fn evaluate(&self, x: &Self::Solution) -> Objectives {
+        let (shared, blocks) = match self.kind {
+            PseudoBooleanKind::Cocz => {
+                let (cooperative, conflicting) = x.split_at(x.len() / 2);
+                (unitation(cooperative), conflicting)
+            }
+            _ => (0, &x[..]),
+        };
+        blocks
+            .chunks(self.block_size())
+            .flat_map(|bits| self.block_objectives(bits, shared))
+            .map(|v| v as f64)
+            .collect()
+    }
+}
+
+impl ParetoFront for PseudoBoolean {
+    /// 離散的なフロントのため `resolution` によらずすべての点を返す
+    ///
+    /// 各ブロックのフロントの直積となる。
+    fn pareto_front(&self, _resolution: usize) -> Vec<Objectives> {
+        let block = self.block_front();
+        (0..self.number_of_obj / 2).fold(vec![Vec::new()], |acc, _| {
+            acc.into_iter()
+                .flat_map(|prefix| {
+                    block.iter().map(move |point| {
+                        let mut next = prefix.clone();
+                        next.extend(point.iter().map(|&v| v as f64));
+                        next
+                    })
+                })
+                .collect()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [PseudoBooleanKind; 3] = [
+        PseudoBooleanKind::OneMinMax,
+        PseudoBooleanKind::Lotz,
+        PseudoBooleanKind::Cocz,
+    ];
+
+    /// すべてのビット列を列挙する
+    fn all_individuals(n: usize) -> Vec<Individual> {
+        (0..1usize << n)
+            .map(|v| (0..n).map(|i| ((v >> i) & 1) as u8).collect())
+            .collect()
+    }
+
+    /// 最大化において `a` が `b` を支配する場合true
+    fn dominates_max(a: &Objectives, b: &Objectives) -> bool {
+        a.iter().zip(b).all(|(x, y)| x >= y)
+            && a.iter().zip(b).any(|(x, y)| x > y)
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let x = vec![1, 1, 0, 1, 0, 0];
+        let omm = PseudoBoolean::new(PseudoBooleanKind::OneMinMax, 6);
+        assert_eq!(omm.evaluate(&x), vec![3.0, 3.0]);
+        let lotz = PseudoBoolean::new(PseudoBooleanKind::Lotz, 6);
+        assert_eq!(lotz.evaluate(&x), vec![2.0, 2.0]);
+        // 前半 [1, 1, 0] の1は2つ、後半 [1, 0, 0] の0は2つ
+        let cocz = PseudoBoolean::new(PseudoBooleanKind::Cocz, 6);
+        assert_eq!(cocz.evaluate(&x), vec![3.0, 4.0]);
+
+        // 4目的では3ビットずつのブロックに分かれる
+        let mlotz =
+            PseudoBoolean::with_objectives(PseudoBooleanKind::Lotz, 6, 4);
+        assert_eq!(mlotz.evaluate(&x), vec![2.0, 1.0, 1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_mcocz() {
+        // 協調部分 [1, 0, 1, 1] の1は3つ。競合部分は [1, 0] と [0, 0]
+        // f1 = 3 + 1, f2 = 3 + 1, f3 = 3 + 0, f4 = 3 + 2
+        let problem =
+            PseudoBoolean::with_objectives(PseudoBooleanKind::Cocz, 8, 4);
+        let x = vec![1, 0, 1, 1, 1, 0, 0, 0];
+        assert_eq!(problem.evaluate(&x), vec![4.0, 4.0, 3.0, 5.0]);
+
+        // フロントは協調部分がすべて1の解で、各ブロックの和は n/2 × 2 + n/m
+        let front = problem.pareto_front(0);
+        assert_eq!(front.len(), 9);
+        assert!(front.contains(&vec![6.0, 4.0, 5.0, 5.0]));
+        front.iter().for_each(|f| {
+            assert_eq!(f[0] + f[1], 10.0);
+            assert_eq!(f[2] + f[3], 10.0);
+        });
+    }
+
+    #[test]
+    fn test_pareto_front_size() {
+        let omm = PseudoBoolean::new(PseudoBooleanKind::OneMinMax, 10);
+        assert_eq!(omm.pareto_front_size(), 11);
+        let cocz = PseudoBoolean::new(PseudoBooleanKind::Cocz, 10);
+        assert_eq!(cocz.pareto_front_size(), 6);
+        let mlotz =
+            PseudoBoolean::with_objectives(PseudoBooleanKind::Lotz, 12, 4);
+        assert_eq!(mlotz.pareto_front_size(), 49);
+        assert_eq!(mlotz.pareto_front(0).len(), 49);
+    }
+
+    #[test]
+    fn test_pareto_front_matches_enumeration() {
+        // 全探索で求めた非支配な目的関数値とフロントが一致する
+        [(8, 2), (8, 4)].iter().for_each(|&(n, m)| {
+            ALL.iter().for_each(|&kind| {
+                let problem = PseudoBoolean::with_objectives(kind, n, m);
+                let values: Vec<Objectives> = all_individuals(n)
+                    .iter()
+                    .map(|x| problem.evaluate(x))
+                    .collect();
+                let mut nondominated: Vec<Objectives> = values
+                    .iter()
+                    .filter(|p| {
+                        !values.iter().any(|q| dominates_max(q, p))
+                    })
+                    .cloned()
+                    .collect();
+                nondominated.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                nondominated.dedup();
+
+                let mut front = problem.pareto_front(0);
+                front.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                assert_eq!(front, nondominated, "{:?} m={}", kind, m);
+                assert_eq!(front.len(), problem.pareto_front_size());
+            });
+        });
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_cocz_odd_block() {
+        PseudoBoolean::with_objectives(PseudoBooleanKind::Cocz, 6, 4);
+    }
+}
@@ -0,0 +1,219 @@
+//! 2目的の集合被覆問題
+//!
+//! 選択した列のコストの合計を最小化し、被覆される行の数を最大化する。
+//! 被覆する行の数の下限を制約とする。既定では下限を0とし、コストと被覆の
+//! トレードオフ全体を探索する。
+
+mod orlib;
+
+use crate::individual::{Individual, Objectives};
+
+use super::{Problem, Sense};
+
+/// 集合被覆問題
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetCovering {
+    /// 行の数
+    pub number_of_rows: usize,
+    /// 各列のコスト
+    pub costs: Vec<u64>,
+    /// 各列が被覆する行
+    pub columns: Vec<Vec<usize>>,
+    /// 被覆しなければならない行の数
+    pub min_coverage: usize,
+}
+
+impl SetCovering {
+    /// 被覆する行の数の下限を0として問題を作る
+    pub fn new(
+        number_of_rows: usize,
+        costs: Vec<u64>,
+        columns: Vec<Vec<usize>>,
+    ) -> Self {
+        assert_eq!(costs.len(), columns.len(), "one cost per column");
+        assert!(
+            columns.iter().flatten().all(|&row| row < number_of_rows),
+            "row index out of range"
+        );
+        Self {
+            number_of_rows,
+            costs,
+            columns,
+            min_coverage: 0,
+        }
+    }
+
+    /// 被覆しなければならない行の数を設定する
+    pub fn with_min_coverage(self, min_coverage: usize) -> Self {
+        assert!(min_coverage <= self.number_of_rows);
+        Self {
+            min_coverage,
+            ..self
+        }
+    }
+
+    /// 列の数
+    pub fn number_of_columns(&self) -> usize {
+        self.costs.len()
+    }
+
+    /// 各行を被覆している選択された列の数
+    pub fn coverage_counts(&self, x: &Individual) -> Vec<usize> {
+        let mut counts = vec![0; self.number_of_rows];
+        x.iter()
+            .zip(&self.columns)
+            .filter(|(b, _)| **b == 1)
+            .flat_map(|(_, rows)| rows)
+            .for_each(|&row| counts[row] += 1);
+        counts
+    }
+
+    /// 被覆されている行の数
+    pub fn covered_rows(&self, x: &Individual) -> usize {
+        self.coverage_counts(x).iter().filter(|&&c| c > 0).count()
+    }
+
+    /// 選択された列のコストの合計
+    pub fn total_cost(&self, x: &Individual) -> u64 {
+        x.iter()
+            .zip(&self.costs)
+            .filter(|(b, _)| **b == 1)
+            .map(|(_, c)| c)
+            .sum()
+    }
+}
+
+impl Problem for SetCovering {
+    type Solution = Individual;
+
+    fn number_of_objectives(&self) -> usize {
+        2
+    }
+
+    fn senses(&self) -> Vec<Sense> {
+        vec![Sense::Minimize, Sense::Maximize]
+    }
+
+    fn evaluate(&self, x: &Self::Solution) -> Objectives {
+        vec![self.total_cost(x) as f64, self.covered_rows(x) as f64]
+    }
+
+    fn number_of_constraints(&self) -> usize {
+        1
+    }
+
+    /// 被覆されている行の数の不足分
+    fn constraint_violation(&self, x: &Self::Solution) -> Vec<f64> {
+        vec![self.min_coverage.saturating_sub(self.covered_rows(x)) as f64]
+    }
+}
+
+/// 貪欲法で実行不可能解を修復する
+///
+/// 被覆する行の数が下限に達するまで、新たに被覆する行1つあたりのコストが
+/// 最も小さい列を加える（Chvátal の貪欲法）。その後、コストの大きい列から順に、
+/// 取り除いても被覆される行が減らない冗長な列を取り除く。
+pub fn repair_set_covering(
+    problem: &SetCovering,
+    x: &Individual,
+) -> Individual {
+    let mut repaired = x.clone();
+    let mut counts = problem.coverage_counts(&repaired);
+    let mut covered = counts.iter().filter(|&&c| c > 0).count();
+
+    while covered < problem.min_coverage {
+        let best = (0..problem.number_of_columns())
+            .filter(|&j| repaired[j] == 0)
+            .map(|j| {
+                let new_rows = problem.columns[j]
+                    .iter()
+                    .filter(|&&row| counts[row] == 0)
+                    .count();
+                (j, problem.costs[j] as f64 / new_rows as f64)
+            })
+            .filter(|(_, ratio)| ratio.is_finite())
+            .min_by(|a, b| a.1.total_cmp(&b.1));
+        let Some((j, _)) = best else {
+            // どの列を加えても被覆される行が増えない
+            break;
+        };
+        repaired[j] = 1;
+        problem.columns[j].iter().for_each(|&row| {
+            if counts[row] == 0 {
+                covered += 1;
+            }
+            counts[row] += 1;
+        });
+    }
+
+    let mut selected: Vec<usize> = (0..problem.number_of_columns())
+        .filter(|&j| repaired[j] == 1)
+        .collect();
+    selected.sort_by(|&a, &b| problem.costs[b].cmp(&problem.costs[a]));
+    for j in selected {
+        if problem.columns[j].iter().all(|&row| counts[row] > 1) {
+            repaired[j] = 0;
+            problem.columns[j].iter().for_each(|&row| counts[row] -= 1);
+        }
+    }
+
+    repaired
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_problem() -> SetCovering {
+        // 列0: {0}, 列1: {0, 1}, 列2: {1}, 列3: {1, 2}
+        SetCovering::new(
+            3,
+            vec![2, 3, 1, 4],
+            vec![vec![0], vec![0, 1], vec![1], vec![1, 2]],
+        )
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let problem = create_problem();
+        let x = vec![1, 0, 1, 0];
+        assert_eq!(problem.evaluate(&x), vec![3.0, 2.0]);
+        assert_eq!(problem.constraint_violation(&x), vec![0.0]);
+        assert_eq!(
+            problem
+                .clone()
+                .with_min_coverage(3)
+                .constraint_violation(&x),
+            vec![1.0]
+        );
+    }
+
+    #[test]
+    fn test_coverage_tradeoff() {
+        // 安い解と多くの行を被覆する解はどちらも実行可能で、互いに支配しない
+        let problem = create_problem();
+        let cheap = problem.evaluate(&vec![0, 0, 1, 0]);
+        let wide = problem.evaluate(&vec![1, 0, 1, 0]);
+        assert_eq!(cheap, vec![1.0, 1.0]);
+        assert_eq!(wide, vec![3.0, 2.0]);
+        assert!(cheap[0] < wide[0] && cheap[1] < wide[1]);
+        assert_eq!(problem.constraint_violation(&vec![0; 4]), vec![0.0]);
+    }
+
+    #[test]
+    fn test_repair_covers_all_rows() {
+        let problem = create_problem().with_min_coverage(3);
+        let repaired = repair_set_covering(&problem, &vec![0; 4]);
+        // 列2, 0, 3 の順に加えた後、冗長になった列2を取り除く
+        assert_eq!(repaired, vec![1, 0, 0, 1]);
+        assert_eq!(problem.constraint_violation(&repaired), vec![0.0]);
+    }
+
+    #[test]
+    fn test_repair_removes_redundant_columns() {
+        // 列1と列2は列0と列3で被覆済みの行しか被覆しない
+        let problem = create_problem().with_min_coverage(3);
+        let repaired = repair_set_covering(&problem, &vec![1; 4]);
+        assert_eq!(repaired, vec![1, 0, 0, 1]);
+    }
+}
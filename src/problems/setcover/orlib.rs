@@ -0,0 +1,98 @@
+//! OR-Library 形式（scp41 など）のインスタンスの読み込み
+//!
+//! ```text
+//! 200 1000
+//!  1 1 1 1 1 1 1 1 ...
+//!  17
+//!  2 8 ...
+//! ```
+//! 行の数 `m` と列の数 `n`、各列のコスト（`n` 個）に続いて、行ごとに
+//! その行を被覆する列の数と列の番号（1始まり）を並べる。
+//! 値は空白で区切られていればよく、改行の位置は問わない。
+
+use std::path::Path;
+
+use crate::problems::parse::{
+    LoadError, next_token, next_value, read_file, syntax_error, tokens,
+};
+
+use super::SetCovering;
+
+impl SetCovering {
+    /// OR-Library 形式のファイルから問題を読み込む
+    pub fn from_orlib_file(path: &Path) -> Result<Self, LoadError> {
+        Self::from_orlib_str(&read_file(path)?)
+    }
+
+    /// OR-Library 形式の文字列から問題を読み込む
+    pub fn from_orlib_str(content: &str) -> Result<Self, LoadError> {
+        let mut tokens = tokens(content);
+        let number_of_rows: usize =
+            next_value(&mut tokens, "number of rows")?;
+        let number_of_columns: usize =
+            next_value(&mut tokens, "number of columns")?;
+
+        let costs = (0..number_of_columns)
+            .map(|_| next_value(&mut tokens, "cost"))
+            .collect::<Result<Vec<u64>, _>>()?;
+
+        let mut columns = vec![Vec::new(); number_of_columns];
+        for row in 0..number_of_rows {
+            let count: usize = next_value(&mut tokens, "row")?;
+            for _ in 0..count {
+                let (line, token) = next_token(&mut tokens, "row")?;
+                let column: usize = token
+                    .parse()
+                    .map_err(|_| syntax_error(line, token))?;
+                if column == 0 || column > number_of_columns {
+                    return Err(LoadError::IndexOutOfRange {
+                        line,
+                        index: column,
+                    });
+                }
+                columns[column - 1].push(row);
+            }
+        }
+
+        Ok(Self::new(number_of_rows, costs, columns))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTENT: &str = " 3 4
+ 2 3 1
+ 4
+ 2
+ 1 2
+ 3 2 3 4
+ 1 4
+";
+
+    #[test]
+    fn test_from_orlib_str() {
+        let problem = SetCovering::from_orlib_str(CONTENT).unwrap();
+        assert_eq!(problem.number_of_rows, 3);
+        assert_eq!(problem.costs, vec![2, 3, 1, 4]);
+        assert_eq!(
+            problem.columns,
+            vec![vec![0], vec![0, 1], vec![1], vec![1, 2]]
+        );
+    }
+
+    #[test]
+    fn test_from_orlib_str_errors() {
+        let truncated = &CONTENT[..CONTENT.len() - 5];
+        assert!(matches!(
+            SetCovering::from_orlib_str(truncated),
+            Err(LoadError::UnexpectedEof { field: "row" })
+        ));
+        let out_of_range = CONTENT.replace(" 1 4\n", " 1 5\n");
+        assert!(matches!(
+            SetCovering::from_orlib_str(&out_of_range),
+            Err(LoadError::IndexOutOfRange { line: 7, index: 5 })
+        ));
+    }
+}
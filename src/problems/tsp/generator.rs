@@ -0,0 +1,137 @@
+//! ランダムなTSPインスタンスの生成
+//!
+//! 乱数には ChaCha8 を用いるので、同じ設定とシードからは `rand` の
+//! バージョンによらず同じインスタンスが生成される。
+
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use super::{MultiObjectiveTsp, tsplib::euclidean_distances};
+
+/// 座標と距離の上限
+const RANGE: u64 = 1000;
+
+/// 距離行列の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DistanceKind {
+    /// 目的関数ごとに `[0, R]²` の一様乱数で座標を生成したユークリッド距離
+    Euclidean,
+    /// 都市の組ごとに `[1, R]` の一様乱数で生成した対称な距離
+    Uniform,
+}
+
+/// TSPインスタンス生成の設定
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TspGeneratorConfig {
+    /// 目的関数の数
+    pub number_of_obj: usize,
+    /// 都市の数
+    pub number_of_cities: usize,
+    /// 距離行列の種類
+    pub kind: DistanceKind,
+    /// 乱数のシード
+    pub seed: u64,
+}
+
+/// 1つの目的関数の距離行列を生成する
+fn random_distances(
+    rng: &mut ChaCha8Rng,
+    number_of_cities: usize,
+    kind: DistanceKind,
+) -> Vec<Vec<u64>> {
+    match kind {
+        DistanceKind::Euclidean => {
+            let coordinates: Vec<(f64, f64)> = (0..number_of_cities)
+                .map(|_| {
+                    (
+                        rng.random_range(0..=RANGE) as f64,
+                        rng.random_range(0..=RANGE) as f64,
+                    )
+                })
+                .collect();
+            euclidean_distances(&coordinates)
+        }
+        DistanceKind::Uniform => {
+            let mut d = vec![vec![0; number_of_cities]; number_of_cities];
+            for i in 0..number_of_cities {
+                for j in i + 1..number_of_cities {
+                    d[i][j] = rng.random_range(1..=RANGE);
+                    d[j][i] = d[i][j];
+                }
+            }
+            d
+        }
+    }
+}
+
+/// 設定に従ってインスタンスを生成する
+pub fn generate_tsp(config: &TspGeneratorConfig) -> MultiObjectiveTsp {
+    let mut rng = ChaCha8Rng::seed_from_u64(config.seed);
+    MultiObjectiveTsp::new(
+        (0..config.number_of_obj)
+            .map(|_| {
+                random_distances(
+                    &mut rng,
+                    config.number_of_cities,
+                    config.kind,
+                )
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_tsp() {
+        let config = TspGeneratorConfig {
+            number_of_obj: 2,
+            number_of_cities: 20,
+            kind: DistanceKind::Uniform,
+            seed: 42,
+        };
+        let tsp = generate_tsp(&config);
+        assert_eq!(tsp.number_of_cities(), 20);
+        // 対称で対角成分は0
+        tsp.distances.iter().for_each(|d| {
+            (0..20).for_each(|i| {
+                assert_eq!(d[i][i], 0);
+                (0..20).for_each(|j| assert_eq!(d[i][j], d[j][i]));
+            });
+        });
+
+        // 同じシードからは同じインスタンス
+        assert_eq!(generate_tsp(&config), tsp);
+    }
+
+    #[test]
+    fn test_generate_tsp_seed() {
+        // 異なるシードからは異なるインスタンス
+        let config = TspGeneratorConfig {
+            number_of_obj: 2,
+            number_of_cities: 20,
+            kind: DistanceKind::Euclidean,
+            seed: 42,
+        };
+        let other = TspGeneratorConfig { seed: 43, ..config };
+        assert_ne!(generate_tsp(&config), generate_tsp(&other));
+    }
+
+    #[test]
+    fn test_generate_tsp_kind() {
+        // 同じシードでも距離行列の種類が異なれば異なるインスタンス
+        let config = TspGeneratorConfig {
+            number_of_obj: 2,
+            number_of_cities: 20,
+            kind: DistanceKind::Uniform,
+            seed: 42,
+        };
+        let other = TspGeneratorConfig {
+            kind: DistanceKind::Euclidean,
+            ..config
+        };
+        assert_ne!(generate_tsp(&config), generate_tsp(&other));
+    }
+}
@@ -0,0 +1,205 @@
+//! 多目的巡回セールスマン問題
+//!
+//! 目的関数ごとに距離行列を持ち、巡回路の各距離行列での長さを最小化する。
+//! 標準的なベンチマーク（kroAB100 など）と同様に、TSPLIB の複数の
+//! インスタンスを組み合わせて作ることができる。
+//!
+//! 巡回路の生成や交叉・突然変異には、順列を保つ
+//! [`crate::operators::random_permutation`] や
+//! [`crate::operators::order_crossover_random`] などを使う。
+
+mod generator;
+mod tsplib;
+
+pub use generator::*;
+pub use tsplib::*;
+
+use std::path::Path;
+
+use crate::individual::Objectives;
+
+use super::{LoadError, Problem, permutation_violation};
+
+/// 都市の訪問順（`0..n` の順列）
+pub type Tour = Vec<usize>;
+
+/// 多目的巡回セールスマン問題
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultiObjectiveTsp {
+    /// 目的関数ごとの距離行列 `distances[k][i][j]`
+    pub distances: Vec<Vec<Vec<u64>>>,
+}
+
+impl MultiObjectiveTsp {
+    /// 距離行列から問題を作る
+    ///
+    /// すべての距離行列は同じ大きさの正方行列でなければならない。
+    pub fn new(distances: Vec<Vec<Vec<u64>>>) -> Self {
+        assert!(!distances.is_empty(), "at least one distance matrix");
+        let n = distances[0].len();
+        assert!(
+            distances.iter().all(
+                |d| d.len() == n && d.iter().all(|row| row.len() == n)
+            ),
+            "distance matrices must be square and of equal size"
+        );
+        Self { distances }
+    }
+
+    /// TSPLIB のインスタンスを組み合わせて問題を作る
+    ///
+    /// 各インスタンスの距離行列がそれぞれ1つの目的関数になる。
+    pub fn from_instances(
+        instances: &[TsplibInstance],
+    ) -> Result<Self, LoadError> {
+        let first = instances.first().ok_or(LoadError::NoInstances)?;
+        let expected = first.coordinates.len();
+        if let Some(other) =
+            instances.iter().find(|i| i.coordinates.len() != expected)
+        {
+            return Err(LoadError::DimensionMismatch {
+                field: "cities",
+                expected,
+                found: other.coordinates.len(),
+            });
+        }
+        Ok(Self::new(
+            instances.iter().map(|i| i.distance_matrix()).collect(),
+        ))
+    }
+
+    /// TSPLIB のファイルを読み込み、組み合わせて問題を作る
+    pub fn from_tsplib_files(paths: &[&Path]) -> Result<Self, LoadError> {
+        let instances = paths
+            .iter()
+            .map(|path| TsplibInstance::from_file(path))
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::from_instances(&instances)
+    }
+
+    /// 都市の数
+    pub fn number_of_cities(&self) -> usize {
+        self.distances[0].len()
+    }
+
+    /// 巡回路の各目的関数での長さ（最後の都市から最初の都市へ戻る）
+    pub fn tour_lengths(&self, tour: &[usize]) -> Vec<u64> {
+        self.distances
+            .iter()
+            .map(|d| {
+                tour.iter()
+                    .zip(tour.iter().cycle().skip(1))
+                    .map(|(&i, &j)| d[i][j])
+                    .sum()
+            })
+            .collect()
+    }
+
+    /// `tour` がすべての都市をちょうど1回ずつ訪れる場合true
+    pub fn is_valid_tour(&self, tour: &[usize]) -> bool {
+        permutation_violation(tour, self.number_of_cities()) == 0
+    }
+}
+
+impl Problem for MultiObjectiveTsp {
+    type Solution = Tour;
+
+    fn number_of_objectives(&self) -> usize {
+        self.distances.len()
+    }
+
+    /// 巡回路が都市の順列でなければ、すべての目的関数値を無限大とする
+    ///
+    /// 順列からの外れ具合は [`Problem::constraint_violation`] で報告する。
+    fn evaluate(&self, x: &Self::Solution) -> Objectives {
+        if !self.is_valid_tour(x) {
+            return vec![f64::INFINITY; self.number_of_objectives()];
+        }
+        self.tour_lengths(x).into_iter().map(|v| v as f64).collect()
+    }
+
+    /// 巡回路が都市の順列であること
+    fn number_of_constraints(&self) -> usize {
+        1
+    }
+
+    fn constraint_violation(&self, x: &Self::Solution) -> Vec<f64> {
+        vec![permutation_violation(x, self.number_of_cities()) as f64]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(scale: f64) -> TsplibInstance {
+        TsplibInstance {
+            name: "square".to_string(),
+            coordinates: vec![
+                (0.0, 0.0),
+                (scale, 0.0),
+                (scale, scale),
+                (0.0, scale),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let tsp = MultiObjectiveTsp::from_instances(&[
+            square(10.0),
+            square(3.0),
+        ])
+        .unwrap();
+        assert_eq!(tsp.number_of_objectives(), 2);
+        // 外周を回る巡回路
+        assert_eq!(tsp.evaluate(&vec![0, 1, 2, 3]), vec![40.0, 12.0]);
+        // 対角線を2回通る巡回路（√200 → 14, √18 → 4）
+        assert_eq!(tsp.evaluate(&vec![0, 2, 1, 3]), vec![48.0, 14.0]);
+    }
+
+    #[test]
+    fn test_from_instances_dimension_mismatch() {
+        let mut small = square(1.0);
+        small.coordinates.pop();
+        assert!(matches!(
+            MultiObjectiveTsp::from_instances(&[square(1.0), small]),
+            Err(LoadError::DimensionMismatch {
+                field: "cities",
+                expected: 4,
+                found: 3
+            })
+        ));
+    }
+
+    #[test]
+    fn test_from_instances_empty() {
+        assert!(matches!(
+            MultiObjectiveTsp::from_instances(&[]),
+            Err(LoadError::NoInstances)
+        ));
+    }
+
+    #[test]
+    fn test_evaluate_invalid_tour() {
+        let tsp =
+            MultiObjectiveTsp::from_instances(&[square(1.0)]).unwrap();
+        assert_eq!(tsp.constraint_violation(&vec![0, 1, 2, 3]), vec![0.0]);
+        // 都市2を訪れず、都市1を2回訪れる
+        let tour = vec![0, 1, 1, 3];
+        assert_eq!(tsp.constraint_violation(&tour), vec![2.0]);
+        assert_eq!(tsp.evaluate(&tour), vec![f64::INFINITY]);
+        // 範囲外の都市でもpanicしない
+        assert_eq!(tsp.evaluate(&vec![0, 1, 2, 4]), vec![f64::INFINITY]);
+    }
+
+    #[test]
+    fn test_is_valid_tour() {
+        let tsp =
+            MultiObjectiveTsp::from_instances(&[square(1.0)]).unwrap();
+        assert!(tsp.is_valid_tour(&[2, 0, 3, 1]));
+        assert!(!tsp.is_valid_tour(&[0, 1, 1, 3]));
+        assert!(!tsp.is_valid_tour(&[0, 1, 2]));
+        assert!(!tsp.is_valid_tour(&[0, 1, 2, 4]));
+    }
+}
@@ -0,0 +1,172 @@
+//! TSPLIB 形式の座標ファイルの読み込み
+//!
+//! `EDGE_WEIGHT_TYPE : EUC_2D` のインスタンスのみに対応する。
+//! ```text
+//! NAME : kroA100
+//! TYPE : TSP
+//! DIMENSION : 100
+//! EDGE_WEIGHT_TYPE : EUC_2D
+//! NODE_COORD_SECTION
+//! 1 1380 939
+//! ...
+//! EOF
+//! ```
+
+use std::path::Path;
+
+use crate::problems::parse::{LoadError, lines, read_file, syntax_error};
+
+/// TSPLIB のインスタンス（都市の座標）
+#[derive(Debug, Clone, PartialEq)]
+pub struct TsplibInstance {
+    pub name: String,
+    pub coordinates: Vec<(f64, f64)>,
+}
+
+impl TsplibInstance {
+    /// ファイルからインスタンスを読み込む
+    pub fn from_file(path: &Path) -> Result<Self, LoadError> {
+        Self::parse(&read_file(path)?)
+    }
+
+    /// 文字列からインスタンスを読み込む
+    pub fn parse(content: &str) -> Result<Self, LoadError> {
+        let mut name = String::new();
+        let mut dimension = None;
+        let mut coordinates = Vec::new();
+        let mut in_coordinates = false;
+
+        for (line_no, line) in lines(content) {
+            if line == "EOF" {
+                break;
+            } else if line == "NODE_COORD_SECTION" {
+                in_coordinates = true;
+            } else if in_coordinates {
+                let values: Vec<f64> = line
+                    .split_whitespace()
+                    .map(|v| v.parse())
+                    .collect::<Result<_, _>>()
+                    .map_err(|_| syntax_error(line_no, line))?;
+                match values[..] {
+                    [_, x, y] => coordinates.push((x, y)),
+                    _ => return Err(syntax_error(line_no, line)),
+                }
+            } else {
+                let (key, value) = line
+                    .split_once(':')
+                    .ok_or_else(|| syntax_error(line_no, line))?;
+                let value = value.trim();
+                match key.trim() {
+                    "NAME" => name = value.to_string(),
+                    "DIMENSION" => {
+                        dimension =
+                            Some(value.parse::<usize>().map_err(
+                                |_| syntax_error(line_no, line),
+                            )?);
+                    }
+                    "TYPE" if value != "TSP" => {
+                        return Err(LoadError::Unsupported {
+                            field: "TYPE",
+                            value: value.to_string(),
+                        });
+                    }
+                    "EDGE_WEIGHT_TYPE" if value != "EUC_2D" => {
+                        return Err(LoadError::Unsupported {
+                            field: "EDGE_WEIGHT_TYPE",
+                            value: value.to_string(),
+                        });
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let dimension = dimension
+            .ok_or(LoadError::MissingField { field: "DIMENSION" })?;
+        if coordinates.len() != dimension {
+            return Err(LoadError::DimensionMismatch {
+                field: "NODE_COORD_SECTION",
+                expected: dimension,
+                found: coordinates.len(),
+            });
+        }
+
+        Ok(Self { name, coordinates })
+    }
+
+    /// TSPLIB の `EUC_2D` の距離行列（ユークリッド距離を最も近い整数に丸める）
+    pub fn distance_matrix(&self) -> Vec<Vec<u64>> {
+        euclidean_distances(&self.coordinates)
+    }
+}
+
+/// 座標から `EUC_2D` の距離行列を求める
+pub(super) fn euclidean_distances(
+    coordinates: &[(f64, f64)],
+) -> Vec<Vec<u64>> {
+    coordinates
+        .iter()
+        .map(|&(x1, y1)| {
+            coordinates
+                .iter()
+                .map(|&(x2, y2)| {
+                    ((x1 - x2).powi(2) + (y1 - y2).powi(2)).sqrt().round()
+                        as u64
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTENT: &str = "NAME : square4
+COMMENT : unit square scaled by 10
+TYPE : TSP
+DIMENSION : 4
+EDGE_WEIGHT_TYPE : EUC_2D
+NODE_COORD_SECTION
+1 0 0
+2 10 0
+3 10 10
+4 0 10
+EOF
+";
+
+    #[test]
+    fn test_parse() {
+        let instance = TsplibInstance::parse(CONTENT).unwrap();
+        assert_eq!(instance.name, "square4");
+        assert_eq!(instance.coordinates.len(), 4);
+        let d = instance.distance_matrix();
+        assert_eq!(d[0][1], 10);
+        // √200 = 14.14... は14に丸める
+        assert_eq!(d[0][2], 14);
+        assert_eq!(d[2][0], 14);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let geo = CONTENT.replace("EUC_2D", "GEO");
+        assert!(matches!(
+            TsplibInstance::parse(&geo),
+            Err(LoadError::Unsupported { .. })
+        ));
+        let short = CONTENT.replace("4 0 10\n", "");
+        assert!(matches!(
+            TsplibInstance::parse(&short),
+            Err(LoadError::DimensionMismatch {
+                field: "NODE_COORD_SECTION",
+                expected: 4,
+                found: 3
+            })
+        ));
+        let broken = CONTENT.replace("2 10 0", "2 10");
+        match TsplibInstance::parse(&broken) {
+            Err(LoadError::Syntax { line, .. }) => assert_eq!(line, 8),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,571 @@
+//! WFG問題群
+//!
+//! Huband, Hingston, Barone, While (2006) による最小化問題。
+//! 変数 `z_i ∈ [0, 2i]` を正規化した後、変換関数を順に適用して
+//! 形状パラメータと距離パラメータに縮約し、形状関数で目的関数値を求める。
+//! 先頭の `k` 個が位置パラメータ、残りの `l` 個が距離パラメータである。
+
+use std::{f64::consts::PI, ops::Range};
+
+use crate::individual::Objectives;
+
+use super::{ParetoFront, Problem, VariableBounds, nondominated_points};
+
+/// WFG問題の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WfgKind {
+    Wfg1,
+    Wfg2,
+    Wfg3,
+    Wfg4,
+    Wfg5,
+    Wfg6,
+    Wfg7,
+    Wfg8,
+    Wfg9,
+}
+
+/// WFG問題
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Wfg {
+    pub kind: WfgKind,
+    pub number_of_obj: usize,
+    /// 位置パラメータの数
+    pub k: usize,
+    /// 距離パラメータの数
+    pub l: usize,
+}
+
+// ---- 変換関数 ----
+
+/// 丸め誤差の許容幅
+const EPSILON: f64 = 1e-10;
+
+/// 丸め誤差で `[0, 1]` をはみ出した値を戻す
+///
+/// 0や1に極めて近い値もそれぞれ0, 1に揃える。`b_poly` のように0付近を
+/// 大きく拡大する変換で、丸め誤差が増幅されないようにするため。
+fn correct_to_01(v: f64) -> f64 {
+    if v.abs() <= EPSILON {
+        0.0
+    } else if (v - 1.0).abs() <= EPSILON {
+        1.0
+    } else {
+        v.clamp(0.0, 1.0)
+    }
+}
+
+/// 多項式バイアス
+fn b_poly(y: f64, alpha: f64) -> f64 {
+    correct_to_01(y.powf(alpha))
+}
+
+/// 平坦領域バイアス
+fn b_flat(y: f64, a: f64, b: f64, c: f64) -> f64 {
+    let v = a + (y - b).floor().min(0.0) * a * (b - y) / b
+        - (c - y).floor().min(0.0) * (1.0 - a) * (y - c) / (1.0 - c);
+    correct_to_01(v)
+}
+
+/// パラメータ依存バイアス
+fn b_param(y: f64, u: f64, a: f64, b: f64, c: f64) -> f64 {
+    let v = b
+        + (c - b) * (a - (1.0 - 2.0 * u) * ((0.5 - u).floor() + a).abs());
+    correct_to_01(y.powf(v))
+}
+
+/// 線形シフト
+fn s_linear(y: f64, a: f64) -> f64 {
+    correct_to_01((y - a).abs() / ((a - y).floor() + a).abs())
+}
+
+/// 欺瞞的シフト
+fn s_deceptive(y: f64, a: f64, b: f64, c: f64) -> f64 {
+    let lower = (y - a + b).floor() * (1.0 - c + (a - b) / b) / (a - b);
+    let upper = (a + b - y).floor() * (1.0 - c + (1.0 - a - b) / b)
+        / (1.0 - a - b);
+    correct_to_01(1.0 + ((y - a).abs() - b) * (lower + upper + 1.0 / b))
+}
+
+/// 多峰シフト
+fn s_multi(y: f64, a: f64, b: f64, c: f64) -> f64 {
+    let t = (y - c).abs() / (2.0 * ((c - y).floor() + c));
+    correct_to_01(
+        (1.0 + ((4.0 * a + 2.0) * PI * (0.5 - t)).cos() + 4.0 * b * t * t)
+            / (b + 2.0),
+    )
+}
+
+/// 重み付き和による縮約
+fn r_sum(y: &[f64], w: &[f64]) -> f64 {
+    let num: f64 = y.iter().zip(w.iter()).map(|(y, w)| y * w).sum();
+    correct_to_01(num / w.iter().sum::<f64>())
+}
+
+/// 非分離な縮約
+fn r_nonsep(y: &[f64], a: usize) -> f64 {
+    let n = y.len();
+    let num: f64 = (0..n)
+        .map(|j| {
+            y[j] + (0..a - 1)
+                .map(|k| (y[j] - y[(j + k + 1) % n]).abs())
+                .sum::<f64>()
+        })
+        .sum();
+    let half = a.div_ceil(2) as f64;
+    let den =
+        n as f64 / a as f64 * half * (1.0 + 2.0 * a as f64 - 2.0 * half);
+    correct_to_01(num / den)
+}
+
+/// `r_sum` の指数をとる `b_param` の定数
+const PARAM_A: f64 = 0.98 / 49.98;
+
+// ---- 形状関数 ----
+
+/// 線形な形状
+fn linear(x: &[f64], i: usize) -> f64 {
+    let m = x.len() + 1;
+    let prod: f64 = x[..m - 1 - i].iter().product();
+    if i == 0 {
+        prod
+    } else {
+        prod * (1.0 - x[m - 1 - i])
+    }
+}
+
+/// 凸な形状
+fn convex(x: &[f64], i: usize) -> f64 {
+    let m = x.len() + 1;
+    let prod: f64 = x[..m - 1 - i]
+        .iter()
+        .map(|v| 1.0 - (v * PI / 2.0).cos())
+        .product();
+    if i == 0 {
+        prod
+    } else {
+        prod * (1.0 - (x[m - 1 - i] * PI / 2.0).sin())
+    }
+}
+
+/// 凹な形状
+fn concave(x: &[f64], i: usize) -> f64 {
+    let m = x.len() + 1;
+    let prod: f64 = x[..m - 1 - i]
+        .iter()
+        .map(|v| (v * PI / 2.0).sin())
+        .product();
+    if i == 0 {
+        prod
+    } else {
+        prod * (x[m - 1 - i] * PI / 2.0).cos()
+    }
+}
+
+/// 凸凹が混在した形状（`A = 5, α = 1`）
+fn mixed(x0: f64) -> f64 {
+    let a = 5.0;
+    1.0 - x0 - (2.0 * a * PI * x0 + PI / 2.0).cos() / (2.0 * a * PI)
+}
+
+/// 非連続な形状（`A = 5, α = β = 1`）
+fn disc(x0: f64) -> f64 {
+    1.0 - x0 * (5.0 * x0 * PI).cos().powi(2)
+}
+
+impl Wfg {
+    /// 位置パラメータ `2(M-1)`、距離パラメータ20で問題を作る
+    pub fn new(kind: WfgKind, number_of_obj: usize) -> Self {
+        Self::with_parameters(
+            kind,
+            number_of_obj,
+            2 * (number_of_obj - 1),
+            20,
+        )
+    }
+
+    /// 位置パラメータと距離パラメータの数を指定して問題を作る
+    ///
+    /// `k` は `M - 1` の倍数、WFG2, 3 の `l` は偶数とする。
+    pub fn with_parameters(
+        kind: WfgKind,
+        number_of_obj: usize,
+        k: usize,
+        l: usize,
+    ) -> Self {
+        assert!(number_of_obj >= 2, "WFG needs at least 2 objectives");
+        assert!(
+            k > 0 && k % (number_of_obj - 1) == 0,
+            "k must be a positive multiple of M - 1"
+        );
+        assert!(l > 0, "WFG needs at least 1 distance parameter");
+        if matches!(kind, WfgKind::Wfg2 | WfgKind::Wfg3) {
+            assert!(l % 2 == 0, "WFG2 and WFG3 need an even l");
+        }
+        Self {
+            kind,
+            number_of_obj,
+            k,
+            l,
+        }
+    }
+
+    /// 位置パラメータを `M - 1` 個のグループに分けた範囲
+    fn position_groups(&self) -> Vec<Range<usize>> {
+        let m = self.number_of_obj - 1;
+        (0..m)
+            .map(|i| i * self.k / m..(i + 1) * self.k / m)
+            .collect()
+    }
+
+    /// 重み付き和で `M` 個のパラメータに縮約する
+    ///
+    /// 距離パラメータは `k` 番目以降すべてを1つにまとめる。
+    fn reduce_sum(&self, y: &[f64], weights: &[f64]) -> Vec<f64> {
+        self.position_groups()
+            .into_iter()
+            .chain(std::iter::once(self.k..y.len()))
+            .map(|r| r_sum(&y[r.clone()], &weights[r]))
+            .collect()
+    }
+
+    /// 非分離な縮約で `M` 個のパラメータに縮約する
+    fn reduce_nonsep(&self, y: &[f64]) -> Vec<f64> {
+        let group = self.k / (self.number_of_obj - 1);
+        self.position_groups()
+            .into_iter()
+            .map(|r| r_nonsep(&y[r], group))
+            .chain(std::iter::once(r_nonsep(&y[self.k..], self.l)))
+            .collect()
+    }
+
+    /// WFG2, 3 の距離パラメータを2つずつ非分離にまとめる
+    fn pairwise_nonsep(&self, y: &[f64]) -> Vec<f64> {
+        y[..self.k]
+            .iter()
+            .copied()
+            .chain(y[self.k..].chunks(2).map(|pair| r_nonsep(pair, 2)))
+            .collect()
+    }
+
+    /// 正規化した変数に変換関数を適用し、`M` 個のパラメータを求める
+    fn transform(&self, y: &[f64]) -> Vec<f64> {
+        let k = self.k;
+        let ones = vec![1.0; y.len()];
+        let distance_linear = |y: &[f64]| -> Vec<f64> {
+            y.iter()
+                .enumerate()
+                .map(|(i, &v)| if i < k { v } else { s_linear(v, 0.35) })
+                .collect()
+        };
+
+        match self.kind {
+            WfgKind::Wfg1 => {
+                let t1 = distance_linear(y);
+                let t2: Vec<f64> = t1
+                    .iter()
+                    .enumerate()
+                    .map(|(i, &v)| {
+                        if i < k { v } else { b_flat(v, 0.8, 0.75, 0.85) }
+                    })
+                    .collect();
+                let t3: Vec<f64> =
+                    t2.iter().map(|&v| b_poly(v, 0.02)).collect();
+                let weights: Vec<f64> =
+                    (1..=y.len()).map(|i| 2.0 * i as f64).collect();
+                self.reduce_sum(&t3, &weights)
+            }
+            WfgKind::Wfg2 | WfgKind::Wfg3 => {
+                let t2 = self.pairwise_nonsep(&distance_linear(y));
+                self.reduce_sum(&t2, &ones)
+            }
+            WfgKind::Wfg4 => {
+                let t1: Vec<f64> = y
+                    .iter()
+                    .map(|&v| s_multi(v, 30.0, 10.0, 0.35))
+                    .collect();
+                self.reduce_sum(&t1, &ones)
+            }
+            WfgKind::Wfg5 => {
+                let t1: Vec<f64> = y
+                    .iter()
+                    .map(|&v| s_deceptive(v, 0.35, 0.001, 0.05))
+                    .collect();
+                self.reduce_sum(&t1, &ones)
+            }
+            WfgKind::Wfg6 => self.reduce_nonsep(&distance_linear(y)),
+            WfgKind::Wfg7 => {
+                let t1: Vec<f64> = (0..y.len())
+                    .map(|i| {
+                        if i < k {
+                            let u = r_sum(&y[i + 1..], &ones[i + 1..]);
+                            b_param(y[i], u, PARAM_A, 0.02, 50.0)
+                        } else {
+                            y[i]
+                        }
+                    })
+                    .collect();
+                self.reduce_sum(&distance_linear(&t1), &ones)
+            }
+            WfgKind::Wfg8 => {
+                let t1: Vec<f64> = (0..y.len())
+                    .map(|i| {
+                        if i < k {
+                            y[i]
+                        } else {
+                            let u = r_sum(&y[..i], &ones[..i]);
+                            b_param(y[i], u, PARAM_A, 0.02, 50.0)
+                        }
+                    })
+                    .collect();
+                self.reduce_sum(&distance_linear(&t1), &ones)
+            }
+            WfgKind::Wfg9 => {
+                let n = y.len();
+                let t1: Vec<f64> = (0..n)
+                    .map(|i| {
+                        if i + 1 < n {
+                            let u = r_sum(&y[i + 1..], &ones[i + 1..]);
+                            b_param(y[i], u, PARAM_A, 0.02, 50.0)
+                        } else {
+                            y[i]
+                        }
+                    })
+                    .collect();
+                let t2: Vec<f64> = t1
+                    .iter()
+                    .enumerate()
+                    .map(|(i, &v)| {
+                        if i < k {
+                            s_deceptive(v, 0.35, 0.001, 0.05)
+                        } else {
+                            s_multi(v, 30.0, 95.0, 0.35)
+                        }
+                    })
+                    .collect();
+                self.reduce_nonsep(&t2)
+            }
+        }
+    }
+
+    /// 退化定数 `A_i`（WFG3 のみ第2成分以降が0）
+    fn degeneracy(&self, i: usize) -> f64 {
+        if self.kind == WfgKind::Wfg3 && i > 0 {
+            0.0
+        } else {
+            1.0
+        }
+    }
+
+    /// 形状関数の値 `h_1, ..., h_M`
+    fn shape(&self, x: &[f64]) -> Vec<f64> {
+        let m = self.number_of_obj;
+        (0..m)
+            .map(|i| match self.kind {
+                WfgKind::Wfg1 if i == m - 1 => mixed(x[0]),
+                WfgKind::Wfg1 => convex(x, i),
+                WfgKind::Wfg2 if i == m - 1 => disc(x[0]),
+                WfgKind::Wfg2 => convex(x, i),
+                WfgKind::Wfg3 => linear(x, i),
+                _ => concave(x, i),
+            })
+            .collect()
+    }
+
+    /// 形状パラメータと距離から目的関数値を求める（`D = 1, S_m = 2m`）
+    fn objectives_from(&self, x: &[f64], distance: f64) -> Objectives {
+        self.shape(x)
+            .iter()
+            .enumerate()
+            .map(|(i, h)| distance + 2.0 * (i + 1) as f64 * h)
+            .collect()
+    }
+
+    /// パレート最適解を作る
+    ///
+    /// `position` は `[0, 1]` に正規化した `k` 個の位置パラメータ。
+    /// 距離パラメータは変換後に0となる値に設定する。
+    pub fn optimal_solution(&self, position: &[f64]) -> Vec<f64> {
+        let n = self.k + self.l;
+        let mut y: Vec<f64> = position.to_vec();
+        y.resize(n, 0.35);
+
+        let exponent = |u: f64| {
+            0.02 + (50.0 - 0.02)
+                * (PARAM_A
+                    - (1.0 - 2.0 * u)
+                        * ((0.5 - u).floor() + PARAM_A).abs())
+        };
+        match self.kind {
+            WfgKind::Wfg8 => (self.k..n).for_each(|i| {
+                let u = y[..i].iter().sum::<f64>() / i as f64;
+                y[i] = 0.35_f64.powf(1.0 / exponent(u));
+            }),
+            WfgKind::Wfg9 => (self.k..n - 1).rev().for_each(|i| {
+                let u =
+                    y[i + 1..].iter().sum::<f64>() / (n - i - 1) as f64;
+                y[i] = 0.35_f64.powf(1.0 / exponent(u));
+            }),
+            _ => {}
+        }
+
+        y.iter()
+            .enumerate()
+            .map(|(i, v)| v * 2.0 * (i + 1) as f64)
+            .collect()
+    }
+}
+
+impl Problem for Wfg {
+    type Solution = Vec<f64>;
+
+    fn number_of_objectives(&self) -> usize {
+        self.number_of_obj
+    }
+
+    fn evaluate(&self, z: &Self::Solution) -> Objectives {
+        let y: Vec<f64> = z
+            .iter()
+            .enumerate()
+            .map(|(i, v)| v / (2.0 * (i + 1) as f64))
+            .collect();
+        let t = self.transform(&y);
+        let m = self.number_of_obj;
+
+        let distance = t[m - 1];
+        let x: Vec<f64> = (0..m - 1)
+            .map(|i| distance.max(self.degeneracy(i)) * (t[i] - 0.5) + 0.5)
+            .collect();
+        self.objectives_from(&x, distance)
+    }
+}
+
+impl VariableBounds for Wfg {
+    fn bounds(&self) -> Vec<(f64, f64)> {
+        (1..=self.k + self.l)
+            .map(|i| (0.0, 2.0 * i as f64))
+            .collect()
+    }
+}
+
+impl ParetoFront for Wfg {
+    /// 形状パラメータの格子点で距離0の目的関数値を求め、非支配なものを返す
+    ///
+    /// WFG3 は退化しているため、第1形状パラメータだけを分割する。
+    fn pareto_front(&self, resolution: usize) -> Vec<Objectives> {
+        let m = self.number_of_obj;
+        let varying = if self.kind == WfgKind::Wfg3 { 1 } else { m - 1 };
+        let d = (1..)
+            .take_while(|d: &usize| {
+                d.pow(varying as u32) <= resolution.max(1)
+            })
+            .last()
+            .unwrap_or(1)
+            .max(2);
+
+        let points: Vec<Objectives> = (0..d.pow(varying as u32))
+            .map(|index| {
+                let x: Vec<f64> = (0..m - 1)
+                    .map(|j| {
+                        if j < varying {
+                            let digit = index / d.pow(j as u32) % d;
+                            digit as f64 / (d - 1) as f64
+                        } else {
+                            0.5
+                        }
+                    })
+                    .collect();
+                self.objectives_from(&x, 0.0)
+            })
+            .collect();
+        nondominated_points(points)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [WfgKind; 9] = [
+        WfgKind::Wfg1,
+        WfgKind::Wfg2,
+        WfgKind::Wfg3,
+        WfgKind::Wfg4,
+        WfgKind::Wfg5,
+        WfgKind::Wfg6,
+        WfgKind::Wfg7,
+        WfgKind::Wfg8,
+        WfgKind::Wfg9,
+    ];
+
+    #[test]
+    fn test_transformations() {
+        // 最適値 0.35 はシフト関数で0に写る
+        assert_eq!(s_linear(0.35, 0.35), 0.0);
+        assert!(s_multi(0.35, 30.0, 10.0, 0.35).abs() < 1e-12);
+        assert!(s_deceptive(0.35, 0.35, 0.001, 0.05).abs() < 1e-12);
+        // 平坦領域 [0.75, 0.85] では一定値 0.8
+        assert_eq!(b_flat(0.8, 0.8, 0.75, 0.85), 0.8);
+        assert!((r_sum(&[0.2, 0.4], &[1.0, 3.0]) - 0.35).abs() < 1e-12);
+        assert_eq!(r_nonsep(&[0.0, 0.0, 0.0], 3), 0.0);
+        assert!((r_nonsep(&[0.2, 0.4], 1) - 0.3).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_bounds() {
+        let problem = Wfg::new(WfgKind::Wfg1, 3);
+        let bounds = problem.bounds();
+        assert_eq!(problem.number_of_variables(), 24);
+        assert_eq!(bounds[0], (0.0, 2.0));
+        assert_eq!(bounds[23], (0.0, 48.0));
+    }
+
+    #[test]
+    fn test_optimal_solutions_lie_on_front() {
+        // WFG3 は Σ f_m / 2m = 1、WFG4–9 は Σ (f_m / 2m)² = 1 を満たす
+        ALL[2..].iter().for_each(|&kind| {
+            let problem = Wfg::new(kind, 3);
+            let position = [0.2, 0.7, 0.4, 0.9];
+            let f = problem.evaluate(&problem.optimal_solution(&position));
+            let scaled = f
+                .iter()
+                .enumerate()
+                .map(|(i, v)| v / (2 * (i + 1)) as f64);
+            let value: f64 = match kind {
+                WfgKind::Wfg3 => scaled.sum(),
+                _ => scaled.map(|v| v * v).sum(),
+            };
+            assert!((value - 1.0).abs() < 1e-6, "{:?}: {}", kind, value);
+        });
+    }
+
+    #[test]
+    fn test_optimal_solutions_have_zero_distance() {
+        // 最適な距離パラメータは変換後に0となる
+        ALL.iter().for_each(|&kind| {
+            [2, 3].iter().for_each(|&m| {
+                let problem = Wfg::new(kind, m);
+                let position = vec![0.3; problem.k];
+                let y: Vec<f64> = problem
+                    .optimal_solution(&position)
+                    .iter()
+                    .enumerate()
+                    .map(|(i, v)| v / (2.0 * (i + 1) as f64))
+                    .collect();
+                let t = problem.transform(&y);
+                assert_eq!(t[m - 1], 0.0, "{:?}, M = {}", kind, m);
+            });
+        });
+    }
+
+    #[test]
+    fn test_pareto_front() {
+        ALL.iter().for_each(|&kind| {
+            let front = Wfg::new(kind, 2).pareto_front(200);
+            assert!(!front.is_empty());
+            assert_eq!(nondominated_points(front.clone()), front);
+        });
+        // WFG2 のフロントは非連続で、支配される点が取り除かれる
+        assert!(Wfg::new(WfgKind::Wfg2, 2).pareto_front(200).len() < 200);
+    }
+}
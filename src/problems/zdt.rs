@@ -0,0 +1,282 @@
+//! ZDT問題群
+//!
+//! Zitzler, Deb, Thiele (2000) による2目的の最小化問題。
+//! ZDT1–4, 6 は実数変数、ZDT5 はビット列で表す。
+
+use std::f64::consts::PI;
+
+use crate::individual::{Individual, Objectives};
+
+use super::{ParetoFront, Problem, VariableBounds, nondominated_points};
+
+/// 実数変数のZDT問題の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ZdtKind {
+    Zdt1,
+    Zdt2,
+    Zdt3,
+    Zdt4,
+    Zdt6,
+}
+
+impl ZdtKind {
+    /// 論文で用いられている変数の数
+    pub fn default_number_of_variables(&self) -> usize {
+        match self {
+            Self::Zdt1 | Self::Zdt2 | Self::Zdt3 => 30,
+            Self::Zdt4 | Self::Zdt6 => 10,
+        }
+    }
+}
+
+/// 実数変数のZDT問題
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Zdt {
+    pub kind: ZdtKind,
+    pub number_of_variables: usize,
+}
+
+impl Zdt {
+    /// 論文と同じ変数の数で問題を作る
+    pub fn new(kind: ZdtKind) -> Self {
+        Self::with_variables(kind, kind.default_number_of_variables())
+    }
+
+    /// 変数の数を指定して問題を作る（2以上）
+    pub fn with_variables(
+        kind: ZdtKind,
+        number_of_variables: usize,
+    ) -> Self {
+        assert!(
+            number_of_variables >= 2,
+            "ZDT needs at least 2 variables"
+        );
+        Self {
+            kind,
+            number_of_variables,
+        }
+    }
+
+    /// 第1目的関数
+    fn f1(&self, x1: f64) -> f64 {
+        match self.kind {
+            ZdtKind::Zdt6 => {
+                1.0 - (-4.0 * x1).exp() * (6.0 * PI * x1).sin().powi(6)
+            }
+            _ => x1,
+        }
+    }
+
+    /// 距離関数 `g`（パレート最適解で1となる）
+    fn g(&self, rest: &[f64]) -> f64 {
+        let n = rest.len() as f64;
+        match self.kind {
+            ZdtKind::Zdt1 | ZdtKind::Zdt2 | ZdtKind::Zdt3 => {
+                1.0 + 9.0 * rest.iter().sum::<f64>() / n
+            }
+            ZdtKind::Zdt4 => {
+                1.0 + 10.0 * n
+                    + rest
+                        .iter()
+                        .map(|x| x * x - 10.0 * (4.0 * PI * x).cos())
+                        .sum::<f64>()
+            }
+            ZdtKind::Zdt6 => {
+                1.0 + 9.0 * (rest.iter().sum::<f64>() / n).powf(0.25)
+            }
+        }
+    }
+
+    /// 形状関数 `h`
+    fn h(&self, f1: f64, g: f64) -> f64 {
+        match self.kind {
+            ZdtKind::Zdt1 | ZdtKind::Zdt4 => 1.0 - (f1 / g).sqrt(),
+            ZdtKind::Zdt2 | ZdtKind::Zdt6 => 1.0 - (f1 / g).powi(2),
+            ZdtKind::Zdt3 => {
+                1.0 - (f1 / g).sqrt() - f1 / g * (10.0 * PI * f1).sin()
+            }
+        }
+    }
+}
+
+impl Problem for Zdt {
+    type Solution = Vec<f64>;
+
+    fn number_of_objectives(&self) -> usize {
+        2
+    }
+
+    fn evaluate(&self, x: &Self::Solution) -> Objectives {
+        let f1 = self.f1(x[0]);
+        let g = self.g(&x[1..]);
+        vec![f1, g * self.h(f1, g)]
+    }
+}
+
+impl VariableBounds for Zdt {
+    fn bounds(&self) -> Vec<(f64, f64)> {
+        let rest = match self.kind {
+            ZdtKind::Zdt4 => (-5.0, 5.0),
+            _ => (0.0, 1.0),
+        };
+        std::iter::once((0.0, 1.0))
+            .chain(std::iter::repeat_n(rest, self.number_of_variables - 1))
+            .collect()
+    }
+}
+
+impl ParetoFront for Zdt {
+    /// `g = 1` として第1目的関数の範囲を等間隔に分割する
+    ///
+    /// ZDT3 は非連続のため、分割した点のうち非支配なものだけを返す。
+    fn pareto_front(&self, resolution: usize) -> Vec<Objectives> {
+        // ZDT6 の第1目的関数の最小値
+        let lower = match self.kind {
+            ZdtKind::Zdt6 => 0.280_775_319_1,
+            _ => 0.0,
+        };
+        let points: Vec<Objectives> = (0..resolution)
+            .map(|i| {
+                let t = i as f64 / (resolution.max(2) - 1) as f64;
+                let f1 = lower + (1.0 - lower) * t;
+                vec![f1, self.h(f1, 1.0)]
+            })
+            .collect();
+
+        match self.kind {
+            ZdtKind::Zdt3 => nondominated_points(points),
+            _ => points,
+        }
+    }
+}
+
+/// ZDT5 の第1変数のビット数
+const ZDT5_FIRST_BITS: usize = 30;
+/// ZDT5 の第2変数以降のビット数
+const ZDT5_REST_BITS: usize = 5;
+
+/// ビット列で表すZDT5問題
+///
+/// 第1変数は30ビット、第2変数以降は5ビットで、既定では変数の数は11。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Zdt5 {
+    pub number_of_variables: usize,
+}
+
+impl Default for Zdt5 {
+    fn default() -> Self {
+        Self {
+            number_of_variables: 11,
+        }
+    }
+}
+
+impl Zdt5 {
+    /// 個体のビット数
+    pub fn number_of_bits(&self) -> usize {
+        ZDT5_FIRST_BITS + ZDT5_REST_BITS * (self.number_of_variables - 1)
+    }
+}
+
+/// 1の数
+fn unitation(bits: &[u8]) -> usize {
+    bits.iter().filter(|&&b| b == 1).count()
+}
+
+impl Problem for Zdt5 {
+    type Solution = Individual;
+
+    fn number_of_objectives(&self) -> usize {
+        2
+    }
+
+    fn evaluate(&self, x: &Self::Solution) -> Objectives {
+        let f1 = 1.0 + unitation(&x[..ZDT5_FIRST_BITS]) as f64;
+        let g: f64 = x[ZDT5_FIRST_BITS..]
+            .chunks(ZDT5_REST_BITS)
+            .map(|bits| match unitation(bits) {
+                ZDT5_REST_BITS => 1.0,
+                u => 2.0 + u as f64,
+            })
+            .sum();
+        vec![f1, g / f1]
+    }
+}
+
+impl ParetoFront for Zdt5 {
+    /// 離散的なフロントのため `resolution` によらずすべての点を返す
+    fn pareto_front(&self, _resolution: usize) -> Vec<Objectives> {
+        let g = (self.number_of_variables - 1) as f64;
+        (1..=ZDT5_FIRST_BITS + 1)
+            .map(|f1| vec![f1 as f64, g / f1 as f64])
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL: [ZdtKind; 5] = [
+        ZdtKind::Zdt1,
+        ZdtKind::Zdt2,
+        ZdtKind::Zdt3,
+        ZdtKind::Zdt4,
+        ZdtKind::Zdt6,
+    ];
+
+    #[test]
+    fn test_bounds() {
+        let zdt4 = Zdt::new(ZdtKind::Zdt4);
+        let bounds = zdt4.bounds();
+        assert_eq!(zdt4.number_of_variables(), 10);
+        assert_eq!(bounds[0], (0.0, 1.0));
+        assert_eq!(bounds[9], (-5.0, 5.0));
+        assert_eq!(Zdt::new(ZdtKind::Zdt1).number_of_variables(), 30);
+    }
+
+    #[test]
+    fn test_optimal_solutions_lie_on_front() {
+        // 第2変数以降を最適値にすると g = 1 となり、フロント上の点になる
+        ALL.iter().for_each(|&kind| {
+            let problem = Zdt::new(kind);
+            let mut x = vec![0.0; problem.number_of_variables];
+            x[0] = 0.3;
+            let f = problem.evaluate(&x);
+            let expected = vec![problem.f1(0.3), problem.h(f[0], 1.0)];
+            assert_eq!(f, expected, "{:?}", kind);
+        });
+    }
+
+    #[test]
+    fn test_pareto_front() {
+        let front = Zdt::new(ZdtKind::Zdt1).pareto_front(101);
+        assert_eq!(front.len(), 101);
+        assert_eq!(front[0], vec![0.0, 1.0]);
+        assert_eq!(front[100], vec![1.0, 0.0]);
+
+        // ZDT3 は非連続で、支配される点が取り除かれる
+        let zdt3 = Zdt::new(ZdtKind::Zdt3).pareto_front(1000);
+        assert!(zdt3.len() < 1000);
+        assert_eq!(nondominated_points(zdt3.clone()), zdt3);
+    }
+
+    #[test]
+    fn test_zdt5() {
+        let problem = Zdt5::default();
+        assert_eq!(problem.number_of_bits(), 80);
+
+        // 第2変数以降がすべて1のとき g = 10
+        let mut x = vec![1; problem.number_of_bits()];
+        x[..ZDT5_FIRST_BITS].fill(0);
+        assert_eq!(problem.evaluate(&x), vec![1.0, 10.0]);
+
+        // すべて0のとき f1 = 1, g = 2 × 10
+        let x = vec![0; problem.number_of_bits()];
+        assert_eq!(problem.evaluate(&x), vec![1.0, 20.0]);
+
+        let front = problem.pareto_front(0);
+        assert_eq!(front.len(), 31);
+        assert_eq!(front[30], vec![31.0, 10.0 / 31.0]);
+    }
+}